@@ -0,0 +1,184 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Library API for embedding cargo-modules's analyses (e.g. in custom linters).
+//!
+//! Unlike the graphs and trees used internally, the returned model is made of owned values
+//! (i.e. paths, kinds, visibilities and edges), rather than of rust-analyzer's `hir` handles,
+//! hence stays valid after the analyzed crate's database has been dropped.
+
+use std::path::PathBuf;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+
+use crate::{
+    analyzer::{self, LoadOptions, load_workspace},
+    graph::{BuildOptions, GraphBuilder},
+    options::{GeneralOptions, ProjectOptions},
+    tree::TreeBuilder,
+};
+
+mod model;
+
+pub use self::model::{Edge, EdgeKind, Item, ItemKind, ItemTree, Visibility};
+
+/// The target of a package to analyze.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+#[non_exhaustive]
+pub enum Target {
+    /// The package's library, or its only binary (if it has no library).
+    #[default]
+    Default,
+    /// The package's library.
+    Lib,
+    /// The package's binary of the given name.
+    Bin(String),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AnalysisOptions {
+    /// Path to the package's Cargo.toml (or to its directory).
+    pub manifest_path: PathBuf,
+
+    /// The package to analyze (see `cargo help pkgid`), if the manifest belongs to a workspace.
+    pub package: Option<String>,
+
+    /// The package's target to analyze.
+    pub target: Target,
+
+    /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
+    pub cfg_test: bool,
+
+    /// Include sysroot crates (`std`, `core` & friends) in analysis.
+    pub sysroot: bool,
+
+    /// Add "uses" edges for the items referenced within function bodies.
+    pub body_deps: bool,
+
+    /// Add "calls" edges for the functions called within function bodies.
+    pub calls: bool,
+}
+
+impl Default for AnalysisOptions {
+    fn default() -> Self {
+        Self {
+            manifest_path: PathBuf::from("."),
+            package: None,
+            target: Target::Default,
+            cfg_test: false,
+            sysroot: false,
+            body_deps: false,
+            calls: false,
+        }
+    }
+}
+
+impl AnalysisOptions {
+    fn project_options(&self) -> ProjectOptions {
+        ProjectOptions {
+            lib: self.target == Target::Lib,
+            bin: match &self.target {
+                Target::Bin(name) => Some(name.clone()),
+                Target::Default | Target::Lib => None,
+            },
+            package: self.package.clone(),
+            no_default_features: false,
+            all_features: false,
+            features: vec![],
+            target: None,
+            manifest_path: self.manifest_path.clone(),
+            project_json: None,
+        }
+    }
+}
+
+/// The result of analyzing a crate.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Analysis {
+    /// The crate's name.
+    pub krate: String,
+
+    /// The crate's items (as well as the external items they depend on), sorted by path.
+    pub items: Vec<Item>,
+
+    /// The edges between the crate's items, sorted by source, target and kind.
+    pub edges: Vec<Edge>,
+
+    /// The crate's hierarchical structure, starting at its root module.
+    pub tree: ItemTree,
+}
+
+impl Analysis {
+    /// Returns the item with the given path, if any.
+    pub fn item(&self, path: &str) -> Option<&Item> {
+        self.items.iter().find(|item| item.path == path)
+    }
+
+    /// Returns the edges originating from the item with the given path.
+    pub fn edges_from<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a Edge> + 'a {
+        self.edges.iter().filter(move |edge| edge.source == path)
+    }
+
+    /// Returns the edges pointing to the item with the given path.
+    pub fn edges_to<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a Edge> + 'a {
+        self.edges.iter().filter(move |edge| edge.target == path)
+    }
+}
+
+/// Loads and analyzes the crate selected by `options`.
+pub fn analyze(options: &AnalysisOptions) -> anyhow::Result<Analysis> {
+    let general_options = GeneralOptions { verbose: false };
+    let project_options = options.project_options();
+
+    let load_options = LoadOptions {
+        cfg_test: options.cfg_test,
+        sysroot: options.sysroot,
+        workspace: false,
+        all_targets: false,
+    };
+
+    let (krate, host, _vfs, edition) =
+        load_workspace(&general_options, &project_options, &load_options)?;
+    let db: &dyn HirDatabase = host.raw_database();
+
+    let build_options = BuildOptions {
+        body_deps: options.body_deps,
+        calls: options.calls,
+    };
+
+    hir::attach_db(db, || {
+        let member_krates = std::slice::from_ref(&krate);
+
+        let (graph, _crate_node_idx) =
+            GraphBuilder::new(db, edition, krate, build_options).build()?;
+        let tree = TreeBuilder::new(db, krate).build()?;
+
+        let mut items: Vec<Item> = graph
+            .node_weights()
+            .map(|node| Item::new(node, member_krates, db, edition))
+            .collect();
+        items.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path));
+
+        let mut edges: Vec<Edge> = graph
+            .edge_indices()
+            .map(|edge_idx| {
+                let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+                Edge {
+                    source: graph[source_idx].display_path(db, edition),
+                    target: graph[target_idx].display_path(db, edition),
+                    kind: EdgeKind::from(graph[edge_idx]),
+                }
+            })
+            .collect();
+        edges.sort();
+
+        Ok(Analysis {
+            krate: analyzer::crate_name(krate, db),
+            items,
+            edges,
+            tree: ItemTree::new(&tree, member_krates, db, edition),
+        })
+    })
+}
@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use crate::{analyzer, graph::Relationship, item::ItemVisibility, tree::Tree};
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[non_exhaustive]
+pub enum ItemKind {
+    Crate,
+    Module,
+    Function,
+    Struct,
+    Union,
+    Enum,
+    Variant,
+    Const,
+    Static,
+    Trait,
+    TypeAlias,
+    Builtin,
+    Macro,
+}
+
+impl ItemKind {
+    fn new(hir: hir::ModuleDef, db: &dyn HirDatabase) -> Self {
+        match hir {
+            hir::ModuleDef::Module(hir) => {
+                if hir.is_crate_root(db) {
+                    Self::Crate
+                } else {
+                    Self::Module
+                }
+            }
+            hir::ModuleDef::Function(_) => Self::Function,
+            hir::ModuleDef::Adt(hir::Adt::Struct(_)) => Self::Struct,
+            hir::ModuleDef::Adt(hir::Adt::Union(_)) => Self::Union,
+            hir::ModuleDef::Adt(hir::Adt::Enum(_)) => Self::Enum,
+            hir::ModuleDef::EnumVariant(_) => Self::Variant,
+            hir::ModuleDef::Const(_) => Self::Const,
+            hir::ModuleDef::Static(_) => Self::Static,
+            hir::ModuleDef::Trait(_) => Self::Trait,
+            hir::ModuleDef::TypeAlias(_) => Self::TypeAlias,
+            hir::ModuleDef::BuiltinType(_) => Self::Builtin,
+            hir::ModuleDef::Macro(_) => Self::Macro,
+        }
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum Visibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)`
+    Crate,
+    /// `pub(in <path>)`
+    Module(String),
+    /// `pub(super)`
+    Super,
+    /// `pub(self)`, or no visibility modifier at all
+    Private,
+}
+
+impl From<ItemVisibility> for Visibility {
+    fn from(visibility: ItemVisibility) -> Self {
+        match visibility {
+            ItemVisibility::Public => Self::Public,
+            ItemVisibility::Crate => Self::Crate,
+            ItemVisibility::Module(path) => Self::Module(path),
+            ItemVisibility::Super => Self::Super,
+            ItemVisibility::Private => Self::Private,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Item {
+    /// The item's canonical path (e.g. `my_crate::foo::Bar`).
+    pub path: String,
+    /// The item's name (e.g. `Bar`).
+    pub name: String,
+    /// The item's kind (e.g. `ItemKind::Struct`).
+    pub kind: ItemKind,
+    /// The item's declared visibility (e.g. `Visibility::Crate` for `pub(crate)`).
+    pub visibility: Visibility,
+    /// The name of the crate the item belongs to (unless it's a builtin type).
+    pub krate: Option<String>,
+    /// Whether the item belongs to a crate other than the analyzed one.
+    pub external: bool,
+    /// The item's `#[cfg(…)]` attributes.
+    pub cfgs: Vec<String>,
+    /// Whether the item is attributed with `#[test]`.
+    pub test: bool,
+}
+
+impl Item {
+    pub(crate) fn new(
+        node: &crate::item::Item,
+        member_krates: &[hir::Crate],
+        db: &dyn HirDatabase,
+        edition: Edition,
+    ) -> Self {
+        let krate = analyzer::krate(node.hir, db);
+        let attrs = node.attrs(db, edition);
+
+        Self {
+            path: node.display_path(db, edition),
+            name: node.display_name(db, edition),
+            kind: ItemKind::new(node.hir, db),
+            visibility: node.visibility(db, edition).into(),
+            krate: krate.map(|krate| analyzer::crate_name(krate, db)),
+            external: !krate.is_some_and(|krate| member_krates.contains(&krate)),
+            cfgs: attrs.cfgs.iter().map(|cfg| cfg.to_string()).collect(),
+            test: attrs.test.is_some(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[non_exhaustive]
+pub enum EdgeKind {
+    Uses,
+    Owns,
+    Reexports,
+    Implements,
+    Calls,
+}
+
+impl From<Relationship> for EdgeKind {
+    fn from(relationship: Relationship) -> Self {
+        match relationship {
+            Relationship::Uses => Self::Uses,
+            Relationship::Owns => Self::Owns,
+            Relationship::Reexports => Self::Reexports,
+            Relationship::Implements => Self::Implements,
+            Relationship::Calls => Self::Calls,
+        }
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Edge {
+    /// The path of the edge's source item.
+    pub source: String,
+    /// The path of the edge's target item.
+    pub target: String,
+    /// The relationship between the source and target items (e.g. `EdgeKind::Uses`).
+    pub kind: EdgeKind,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ItemTree {
+    pub item: Item,
+    pub subtrees: Vec<ItemTree>,
+}
+
+impl ItemTree {
+    pub(crate) fn new(
+        tree: &Tree<crate::item::Item>,
+        member_krates: &[hir::Crate],
+        db: &dyn HirDatabase,
+        edition: Edition,
+    ) -> Self {
+        Self {
+            item: Item::new(&tree.node, member_krates, db, edition),
+            subtrees: tree
+                .subtrees
+                .iter()
+                .map(|subtree| Self::new(subtree, member_krates, db, edition))
+                .collect(),
+        }
+    }
+}
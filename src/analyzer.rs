@@ -4,8 +4,10 @@
 
 use std::path::{Path, PathBuf};
 
+use ra_ap_base_db::{self as base_db, salsa::Setter as _};
 use ra_ap_cfg::{self as cfg};
 use ra_ap_hir::{self as hir, AsAssocItem as _, HasAttrs as _, db::HirDatabase};
+use ra_ap_hir_def::{self as hir_def};
 use ra_ap_ide::{self as ide};
 use ra_ap_ide_db::{self as ide_db};
 use ra_ap_load_cargo::{self as load_cargo};
@@ -25,14 +27,40 @@ pub struct LoadOptions {
 
     /// Include sysroot crates (`std`, `core` & friends) in analysis.
     pub sysroot: bool,
+
+    /// Analyze all of the workspace's member packages, rather than a single one.
+    pub workspace: bool,
+
+    /// Analyze all of the package's targets, rather than a single one.
+    pub all_targets: bool,
 }
 
+/// Loads the workspace, returning the (single) crate selected for analysis.
 pub fn load_workspace(
     general_options: &GeneralOptions,
     project_options: &ProjectOptions,
     load_options: &LoadOptions,
 ) -> anyhow::Result<(hir::Crate, ide::AnalysisHost, vfs::Vfs, ide::Edition)> {
-    let project_path = project_options.manifest_path.as_path().canonicalize()?;
+    let (krates, host, vfs, edition) =
+        load_workspace_crates(general_options, project_options, load_options)?;
+
+    Ok((krates[0], host, vfs, edition))
+}
+
+/// Loads the workspace, returning the crates selected for analysis.
+///
+/// Unless `load_options.workspace` or `load_options.all_targets` is set,
+/// exactly one crate gets selected.
+pub fn load_workspace_crates(
+    general_options: &GeneralOptions,
+    project_options: &ProjectOptions,
+    load_options: &LoadOptions,
+) -> anyhow::Result<(Vec<hir::Crate>, ide::AnalysisHost, vfs::Vfs, ide::Edition)> {
+    let project_path = project_options
+        .project_json
+        .as_deref()
+        .unwrap_or(project_options.manifest_path.as_path())
+        .canonicalize()?;
 
     // See: https://github.com/rust-lang/cargo/pull/13909
     // The `canonicalize` func on windows will return `r"\\?\"` verbatim prefix.
@@ -47,19 +75,57 @@ pub fn load_workspace(
         tracing::trace!("Progress: {}", string);
     };
 
-    let mut project_workspace = load_project_workspace(&project_path, &cargo_config, &progress)?;
+    let mut project_workspace = if project_options.project_json.is_some() {
+        load_project_json_workspace(&project_path, &cargo_config, &progress)?
+    } else {
+        load_project_workspace(&project_path, &cargo_config, &progress)?
+    };
+
+    // Non-Cargo projects have no packages and targets to select from:
+    if project_workspace.is_json() {
+        return load_project_json_crates(
+            general_options,
+            project_options,
+            load_options,
+            project_workspace,
+            &cargo_config,
+            &load_config,
+        );
+    }
 
-    let (package, target) = select_package_and_target(&project_workspace, project_options)?;
+    // Neither do standalone files:
+    if let project_model::ProjectWorkspaceKind::DetachedFile { .. } = project_workspace.kind {
+        return load_detached_file_crate(
+            general_options,
+            load_options,
+            project_workspace,
+            &cargo_config,
+            &load_config,
+        );
+    }
+
+    let packages_and_targets = if load_options.workspace {
+        select_members_and_targets(&project_workspace, project_options)?
+    } else if load_options.all_targets {
+        select_package_and_all_targets(&project_workspace, project_options)?
+    } else {
+        vec![select_package_and_target(
+            &project_workspace,
+            project_options,
+        )?]
+    };
 
     if general_options.verbose {
-        eprintln!();
-        eprintln!("crate");
-        eprintln!("└── package: {}", package.name);
-        eprintln!("    └── target: {}", target.name);
+        for (package, target) in &packages_and_targets {
+            eprintln!();
+            eprintln!("crate");
+            eprintln!("└── package: {}", package.name);
+            eprintln!("    └── target: {}", target.name);
+        }
         eprintln!();
     }
 
-    let edition = package.edition;
+    let edition = packages_and_targets[0].0.edition;
 
     if load_config.load_out_dirs_from_check {
         let build_scripts = project_workspace.run_build_scripts(&cargo_config, &progress)?;
@@ -69,18 +135,250 @@ pub fn load_workspace(
     let (db, vfs, _proc_macro_client) =
         ra_ap_load_cargo::load_workspace(project_workspace, &cargo_config.extra_env, &load_config)?;
 
+    let mut host = ide::AnalysisHost::with_database(db);
+
+    let krates = packages_and_targets
+        .iter()
+        .map(|(_package, target)| find_crate(host.raw_database(), &vfs, target))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if load_options.all_targets {
+        disambiguate_crate_names(host.raw_database_mut(), &krates, &packages_and_targets);
+    }
+
+    Ok((krates, host, vfs, edition))
+}
+
+/// Loads the non-Cargo (i.e. `rust-project.json`) project, returning the crates selected for analysis.
+///
+/// Unless `load_options.workspace` is set, exactly one of the project's member crates gets selected.
+fn load_project_json_crates(
+    general_options: &GeneralOptions,
+    project_options: &ProjectOptions,
+    load_options: &LoadOptions,
+    project_workspace: project_model::ProjectWorkspace,
+    cargo_config: &project_model::CargoConfig,
+    load_config: &load_cargo::LoadCargoConfig,
+) -> anyhow::Result<(Vec<hir::Crate>, ide::AnalysisHost, vfs::Vfs, ide::Edition)> {
+    let project_model::ProjectWorkspaceKind::Json(ref project_json) = project_workspace.kind else {
+        anyhow::bail!("Expected a JSON workspace");
+    };
+
+    if load_options.all_targets {
+        anyhow::bail!("Selecting all targets is not supported for rust-project.json projects");
+    }
+
+    let project_root = project_json.project_root().to_path_buf();
+
+    let member_roots: Vec<_> = project_json
+        .crates()
+        .filter(|(_, krate)| krate.is_workspace_member)
+        .map(|(_, krate)| krate.root_module.clone())
+        .collect();
+
+    let (db, vfs, _proc_macro_client) =
+        ra_ap_load_cargo::load_workspace(project_workspace, &cargo_config.extra_env, load_config)?;
+
+    let host = ide::AnalysisHost::with_database(db);
+    let db = host.raw_database();
+
+    let members = member_roots
+        .iter()
+        .map(|root| {
+            let krate = find_crate_by_root(db, &vfs, root)?;
+            let relative_root = root.strip_prefix(&project_root).map_or_else(
+                || root.to_string(),
+                |relative_root| relative_root.as_utf8_path().to_string(),
+            );
+            Ok((krate, relative_root))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let krates = if load_options.workspace {
+        members.iter().map(|(krate, _)| *krate).collect()
+    } else {
+        vec![select_project_json_crate(
+            &members,
+            db,
+            &vfs,
+            project_options,
+        )?]
+    };
+
+    if krates.is_empty() {
+        anyhow::bail!("no crates found");
+    }
+
+    if general_options.verbose {
+        for krate in &krates {
+            eprintln!();
+            eprintln!("crate");
+            eprintln!("└── name: {}", crate_name(*krate, db));
+        }
+        eprintln!();
+    }
+
+    let edition = krates[0].edition(db);
+
+    Ok((krates, host, vfs, edition))
+}
+
+/// Loads the standalone (i.e. detached) `.rs` file, returning its crate.
+fn load_detached_file_crate(
+    general_options: &GeneralOptions,
+    load_options: &LoadOptions,
+    project_workspace: project_model::ProjectWorkspace,
+    cargo_config: &project_model::CargoConfig,
+    load_config: &load_cargo::LoadCargoConfig,
+) -> anyhow::Result<(Vec<hir::Crate>, ide::AnalysisHost, vfs::Vfs, ide::Edition)> {
+    let project_model::ProjectWorkspaceKind::DetachedFile { ref file, .. } = project_workspace.kind
+    else {
+        anyhow::bail!("Expected a detached file");
+    };
+
+    if load_options.workspace || load_options.all_targets {
+        anyhow::bail!("Selecting multiple crates is not supported for standalone files");
+    }
+
+    let file = file.to_path_buf();
+
+    let (db, vfs, _proc_macro_client) =
+        ra_ap_load_cargo::load_workspace(project_workspace, &cargo_config.extra_env, load_config)?;
+
     let host = ide::AnalysisHost::with_database(db);
+    let db = host.raw_database();
+
+    let krate = find_crate_by_root(db, &vfs, &file)?;
+
+    if general_options.verbose {
+        eprintln!();
+        eprintln!("crate");
+        eprintln!("└── file: {file}");
+        eprintln!();
+    }
 
-    let krate = find_crate(host.raw_database(), &vfs, &target)?;
+    let edition = krate.edition(db);
 
-    Ok((krate, host, vfs, edition))
+    Ok((vec![krate], host, vfs, edition))
+}
+
+/// Selects the member crate provided via `--package`, either by name or by its root file.
+pub fn select_project_json_crate(
+    members: &[(hir::Crate, String)],
+    db: &ide::RootDatabase,
+    vfs: &vfs::Vfs,
+    options: &ProjectOptions,
+) -> anyhow::Result<hir::Crate> {
+    // If project contains no crates, bail out:
+
+    if members.is_empty() {
+        anyhow::bail!("no crates found");
+    }
+
+    let crate_list_items: Vec<_> = members
+        .iter()
+        .map(|(krate, relative_root)| format!("- {} ({})", crate_name(*krate, db), relative_root))
+        .collect();
+
+    let crate_list = crate_list_items.join("\n");
+
+    // If project contains multiple crates, select the one provided via options:
+
+    if let Some(selector) = &options.package {
+        let crate_name_selector = selector.replace('-', "_");
+        let root_selector = Path::new(selector).canonicalize().ok();
+
+        let krate = members.iter().find(|(krate, _)| {
+            if crate_name(*krate, db) == crate_name_selector {
+                return true;
+            }
+
+            let vfs_path = vfs.file_path(krate.root_file(db));
+            let crate_root_path = vfs_path.as_path().unwrap();
+
+            root_selector.as_deref().is_some_and(|root_selector| {
+                AsRef::<Path>::as_ref(crate_root_path) == root_selector
+            })
+        });
+
+        return krate.map(|(krate, _)| *krate).ok_or_else(|| {
+            anyhow::anyhow!(
+                indoc::indoc! {
+                    "No crate found with name or root file {:?}.
+
+                        Crates present in project:
+                        {}
+                        "
+                },
+                selector,
+                crate_list,
+            )
+        });
+    }
+
+    // If project contains a single crate, just pick it:
+
+    if members.len() == 1 {
+        return Ok(members[0].0);
+    }
+
+    Err(anyhow::anyhow!(
+        indoc::indoc! {
+            "Multiple crates present in project,
+                please explicitly select one via --package flag.
+
+                Crates present in project:
+                {}
+                "
+        },
+        crate_list
+    ))
+}
+
+/// Renames the non-library crates that share their name with the package's library crate
+/// (e.g. the `src/main.rs` binary), whose items' paths would otherwise be indistinguishable,
+/// by suffixing them with their target's kind (e.g. `foo-bin`).
+fn disambiguate_crate_names(
+    db: &mut ide::RootDatabase,
+    krates: &[hir::Crate],
+    packages_and_targets: &[(project_model::PackageData, project_model::TargetData)],
+) {
+    let lib_name = krates
+        .iter()
+        .zip(packages_and_targets)
+        .find(|(_, (_, target))| matches!(target.kind, project_model::TargetKind::Lib { .. }))
+        .map(|(krate, _)| crate_name(*krate, db));
+
+    let Some(lib_name) = lib_name else {
+        return;
+    };
+
+    for (krate, (_, target)) in krates.iter().zip(packages_and_targets) {
+        if matches!(target.kind, project_model::TargetKind::Lib { .. })
+            || crate_name(*krate, db) != lib_name
+        {
+            continue;
+        }
+
+        let Some(kind_name) = target.kind.as_cargo_target() else {
+            continue;
+        };
+
+        let display_name = format!("{}-{kind_name}", target.name);
+
+        let mut extra_data = krate.base().extra_data(db).clone();
+        extra_data.display_name = Some(base_db::CrateDisplayName::from_canonical_name(
+            &display_name,
+        ));
+        krate.base().set_extra_data(db).to(extra_data);
+    }
 }
 
 pub fn cargo_config(
     project_options: &ProjectOptions,
     load_options: &LoadOptions,
 ) -> project_model::CargoConfig {
-    let all_targets = false;
+    let all_targets = load_options.all_targets;
 
     // Crates to enable/disable `#[cfg(test)]` on
     let cfg_overrides = match load_options.cfg_test {
@@ -181,6 +479,19 @@ pub fn load_config() -> load_cargo::LoadCargoConfig {
     }
 }
 
+pub fn load_project_json_workspace(
+    project_json_path: &Path,
+    cargo_config: &project_model::CargoConfig,
+    progress: &(dyn Fn(String) + Sync),
+) -> anyhow::Result<project_model::ProjectWorkspace> {
+    let utf8_path_buf = paths::Utf8PathBuf::from_path_buf(project_json_path.to_path_buf()).unwrap();
+    let manifest = project_model::ManifestPath::try_from(paths::AbsPathBuf::assert(utf8_path_buf))
+        .map_err(|path| anyhow::anyhow!("bad rust-project.json path: {path}"))?;
+    let root = project_model::ProjectManifest::ProjectJson(manifest);
+
+    project_model::ProjectWorkspace::load(root, cargo_config, &progress)
+}
+
 pub fn load_project_workspace(
     project_path: &Path,
     cargo_config: &project_model::CargoConfig,
@@ -189,7 +500,13 @@ pub fn load_project_workspace(
     let path_buf = std::env::current_dir()?.join(project_path);
     let utf8_path_buf = paths::Utf8PathBuf::from_path_buf(path_buf).unwrap();
     let root = paths::AbsPathBuf::assert(utf8_path_buf);
-    let root = project_model::ProjectManifest::discover_single(root.as_path())?;
+
+    // Standalone `.rs` files (e.g. scripts) get loaded as detached files:
+    let root = if root.extension() == Some("rs") {
+        project_model::ProjectManifest::from_manifest_file(root)?
+    } else {
+        project_model::ProjectManifest::discover_single(root.as_path())?
+    };
 
     project_model::ProjectWorkspace::load(root, cargo_config, &progress)
 }
@@ -219,6 +536,111 @@ pub fn select_package_and_target(
     Ok((package, target))
 }
 
+/// Selects the library target of each of the workspace's member packages
+/// (or their binary targets, for packages without a library target).
+pub fn select_members_and_targets(
+    project_workspace: &project_model::ProjectWorkspace,
+    options: &ProjectOptions,
+) -> anyhow::Result<Vec<(project_model::PackageData, project_model::TargetData)>> {
+    let project_model::ProjectWorkspaceKind::Cargo { ref cargo, .. } = project_workspace.kind
+    else {
+        anyhow::bail!("Expected a Cargo workspace");
+    };
+
+    let mut packages_and_targets = vec![];
+
+    for package_idx in cargo.packages().filter(|idx| cargo[*idx].is_member) {
+        let package = &cargo[package_idx];
+
+        let targets: Vec<_> = package
+            .targets
+            .iter()
+            .map(|target_idx| &cargo[*target_idx])
+            .filter(|target| {
+                matches!(
+                    target.kind,
+                    project_model::TargetKind::Lib { .. } | project_model::TargetKind::Bin
+                )
+            })
+            .collect();
+
+        let has_lib = targets
+            .iter()
+            .any(|target| matches!(target.kind, project_model::TargetKind::Lib { .. }));
+
+        for target in targets {
+            let is_lib = matches!(target.kind, project_model::TargetKind::Lib { .. });
+
+            if options.lib && !is_lib {
+                continue;
+            }
+
+            if has_lib && !is_lib {
+                continue;
+            }
+
+            tracing::debug!(
+                "Selected package {:#?}, target {:#?}",
+                package.name,
+                target.name
+            );
+
+            packages_and_targets.push((package.clone(), target.clone()));
+        }
+    }
+
+    if packages_and_targets.is_empty() {
+        anyhow::bail!("no targets found");
+    }
+
+    Ok(packages_and_targets)
+}
+
+/// Selects all of the package's targets (i.e. its library, binaries, examples, tests and benches),
+/// with its library target (if any) coming first.
+pub fn select_package_and_all_targets(
+    project_workspace: &project_model::ProjectWorkspace,
+    options: &ProjectOptions,
+) -> anyhow::Result<Vec<(project_model::PackageData, project_model::TargetData)>> {
+    let project_model::ProjectWorkspaceKind::Cargo { ref cargo, .. } = project_workspace.kind
+    else {
+        anyhow::bail!("Expected a Cargo workspace");
+    };
+
+    let package_idx = select_package(cargo, options)?;
+    let package = &cargo[package_idx];
+    tracing::debug!("Selected package: {:#?}", package.name);
+
+    let mut targets: Vec<_> = package
+        .targets
+        .iter()
+        .map(|target_idx| &cargo[*target_idx])
+        .filter(|target| match target.kind {
+            project_model::TargetKind::Bin => true,
+            project_model::TargetKind::Lib { .. } => true,
+            project_model::TargetKind::Example => true,
+            project_model::TargetKind::Test => true,
+            project_model::TargetKind::Bench => true,
+            project_model::TargetKind::Other => false,
+            project_model::TargetKind::BuildScript => false,
+        })
+        .collect();
+
+    if targets.is_empty() {
+        anyhow::bail!("no targets found");
+    }
+
+    targets.sort_by_key(|target| !matches!(target.kind, project_model::TargetKind::Lib { .. }));
+
+    Ok(targets
+        .into_iter()
+        .map(|target| {
+            tracing::debug!("Selected target: {:#?}", target.name);
+            (package.clone(), target.clone())
+        })
+        .collect())
+}
+
 pub fn select_package(
     workspace: &project_model::CargoWorkspace,
     options: &ProjectOptions,
@@ -414,9 +836,15 @@ pub fn find_crate(
     vfs: &vfs::Vfs,
     target: &project_model::TargetData,
 ) -> anyhow::Result<hir::Crate> {
-    let crates = hir::Crate::all(db);
+    find_crate_by_root(db, vfs, target.root.as_path())
+}
 
-    let target_root_path = target.root.as_path();
+pub fn find_crate_by_root(
+    db: &ide::RootDatabase,
+    vfs: &vfs::Vfs,
+    target_root_path: &paths::AbsPath,
+) -> anyhow::Result<hir::Crate> {
+    let crates = hir::Crate::all(db);
 
     let krate = crates.into_iter().find(|krate| {
         let vfs_path = vfs.file_path(krate.root_file(db));
@@ -447,6 +875,46 @@ pub(crate) fn module(module_def_hir: hir::ModuleDef, db: &dyn HirDatabase) -> Op
     }
 }
 
+/// Returns the named items of the module's scope (i.e. both its declarations and its imports)
+/// that are nameable from outside of its crate (i.e. `pub` declarations and `pub use` imports).
+pub(crate) fn public_scope(
+    module_hir: hir::Module,
+    db: &dyn HirDatabase,
+    edition: ide::Edition,
+) -> Vec<(String, hir::ModuleDef)> {
+    // Unlike `hir::Module::scope`, the module's item scope
+    // provides the visibilities of imports, not just those of declarations:
+    let def_map = hir_def::nameres::crate_def_map(db, module_hir.krate(db).base());
+    let module_id: hir_def::ModuleId = module_hir.into();
+
+    let mut scope: Vec<(String, hir::ModuleDef)> = def_map[module_id]
+        .scope
+        .entries()
+        .flat_map(|(name, per_ns)| {
+            let per_ns =
+                per_ns.filter_visibility(|vis| vis == hir_def::visibility::Visibility::Public);
+            let name = name.display(db, edition).to_string();
+
+            let types = per_ns.types.map(|item| hir::ModuleDef::from(item.def));
+            let values = per_ns.values.map(|item| hir::ModuleDef::from(item.def));
+            let macros = per_ns
+                .macros
+                .map(|item| hir::ModuleDef::Macro(item.def.into()));
+
+            [types, values, macros]
+                .into_iter()
+                .flatten()
+                .map(move |module_def_hir| (name.clone(), module_def_hir))
+        })
+        .collect();
+
+    // Items such as unit structs live in both the type and the value namespace:
+    scope.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+    scope.dedup();
+
+    scope
+}
+
 pub(crate) fn display_name(
     module_def_hir: hir::ModuleDef,
     db: &dyn HirDatabase,
@@ -513,7 +981,10 @@ pub(crate) fn path(
     let relative_path = match module_def_hir {
         hir::ModuleDef::Function(function_hir) => {
             if let Some(assoc_item_hir) = function_hir.as_assoc_item(db) {
-                assoc_item_path(assoc_item_hir, db, edition)
+                // Methods of unnamed types (e.g. slices) have no path of their own,
+                // so we bail out, rather than falling back to their crate's path:
+                let relative_path = assoc_item_path(assoc_item_hir, db, edition)?;
+                Some(relative_path)
             } else {
                 hir::ModuleDef::Function(function_hir).canonical_path(db, edition)
             }
@@ -571,10 +1042,16 @@ fn assoc_item_path(
         hir::AssocItemContainer::Trait(trait_hir) => {
             hir::ModuleDef::Trait(trait_hir).canonical_path(db, edition)
         }
-        hir::AssocItemContainer::Impl(impl_hir) => impl_hir
-            .self_ty(db)
-            .as_adt()
-            .and_then(|adt_hir| hir::ModuleDef::Adt(adt_hir).canonical_path(db, edition)),
+        hir::AssocItemContainer::Impl(impl_hir) => {
+            let self_ty = impl_hir.self_ty(db);
+            if let Some(adt_hir) = self_ty.as_adt() {
+                hir::ModuleDef::Adt(adt_hir).canonical_path(db, edition)
+            } else {
+                self_ty.as_builtin().map(|builtin_type_hir| {
+                    builtin_type_hir.name().display(db, edition).to_string()
+                })
+            }
+        }
     };
 
     let container_path = container_path?;
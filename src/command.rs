@@ -7,16 +7,26 @@ use ra_ap_hir::db::HirDatabase;
 use ra_ap_ide::RootDatabase;
 
 use crate::{
-    analyzer::{LoadOptions, load_workspace},
+    analyzer::{LoadOptions, load_workspace_crates},
     options::{GeneralOptions, ProjectOptions},
 };
 
 use self::{
-    dependencies::command::Command as DependenciesCommand,
+    api::command::Command as ApiCommand, calls::command::Command as CallsCommand,
+    check::command::Command as CheckCommand, cycles::command::Command as CyclesCommand,
+    dependencies::command::Command as DependenciesCommand, diff::command::Command as DiffCommand,
+    html::command::Command as HtmlCommand, metrics::command::Command as MetricsCommand,
     orphans::command::Command as OrphansCommand, structure::command::Command as StructureCommand,
 };
 
+pub mod api;
+pub mod calls;
+pub mod check;
+pub mod cycles;
 pub mod dependencies;
+pub mod diff;
+pub mod html;
+pub mod metrics;
 pub mod orphans;
 pub mod structure;
 
@@ -48,6 +58,52 @@ pub enum Command {
         about = "Detects unlinked source files within a crate's directory."
     )]
     Orphans(OrphansCommand),
+
+    #[command(
+        name = "html",
+        about = "Writes a crate's structure and dependencies as a self-contained, interactive HTML page."
+    )]
+    Html(HtmlCommand),
+
+    #[command(
+        name = "check",
+        about = "Checks a crate's internal dependencies against a set of layering rules."
+    )]
+    Check(CheckCommand),
+
+    #[command(
+        name = "cycles",
+        about = "Reports all of a crate's internal dependency cycles."
+    )]
+    Cycles(CyclesCommand),
+
+    #[command(
+        name = "metrics",
+        about = "Prints coupling, instability and abstractness metrics for a crate's modules."
+    )]
+    Metrics(MetricsCommand),
+
+    #[command(
+        name = "diff",
+        about = "Prints the differences between a crate's graph and that of another checkout of it."
+    )]
+    Diff(DiffCommand),
+
+    #[command(
+        name = "api",
+        about = "Lists the items that are reachable from outside of a crate, by their public paths."
+    )]
+    Api(ApiCommand),
+
+    #[command(
+        name = "calls",
+        about = "Prints a crate's function call graph.",
+        after_help = r#"
+        If you have xdot installed on your system, you can run this using:
+        `cargo modules calls | xdot -`
+        "#
+    )]
+    Calls(CallsCommand),
 }
 
 impl Command {
@@ -56,16 +112,45 @@ impl Command {
             Self::Structure(command) => command.sanitize(),
             Self::Dependencies(command) => command.sanitize(),
             Self::Orphans(command) => command.sanitize(),
+            Self::Html(command) => command.sanitize(),
+            Self::Check(command) => command.sanitize(),
+            Self::Cycles(command) => command.sanitize(),
+            Self::Metrics(command) => command.sanitize(),
+            Self::Diff(command) => command.sanitize(),
+            Self::Api(command) => command.sanitize(),
+            Self::Calls(command) => command.sanitize(),
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), anyhow::Error> {
+        match self {
+            Self::Structure(_) => Ok(()),
+            Self::Dependencies(command) => command.validate(),
+            Self::Orphans(_) => Ok(()),
+            Self::Html(_) => Ok(()),
+            Self::Check(command) => command.validate(),
+            Self::Cycles(_) => Ok(()),
+            Self::Metrics(_) => Ok(()),
+            Self::Diff(_) => Ok(()),
+            Self::Api(_) => Ok(()),
+            Self::Calls(_) => Ok(()),
         }
     }
 
     pub fn run(self) -> Result<(), anyhow::Error> {
+        // Fail early, rather than after having spent time on analyzing the crate:
+        self.validate()?;
+
         let general_options = self.general_options();
         let project_options = self.project_options();
         let load_options = self.load_options();
 
-        let (krate, host, vfs, edition) =
-            load_workspace(general_options, project_options, &load_options)?;
+        let (krates, host, vfs, edition) =
+            load_workspace_crates(general_options, project_options, &load_options)?;
+
+        // Only the `dependencies` command supports analyzing multiple crates at once,
+        // all other commands get exactly one crate selected for them:
+        let krate = krates[0];
         let db: &RootDatabase = host.raw_database();
         let hir_db: &dyn HirDatabase = db;
 
@@ -73,9 +158,16 @@ impl Command {
             #[allow(unused_variables)]
             Self::Structure(command) => command.run(krate, db, edition),
             #[allow(unused_variables)]
-            Self::Dependencies(command) => command.run(krate, db, edition),
+            Self::Dependencies(command) => command.run(&krates, db, edition),
             #[allow(unused_variables)]
             Self::Orphans(command) => command.run(krate, db, &vfs, edition),
+            Self::Html(command) => command.run(krate, db, edition),
+            Self::Check(command) => command.run(krate, db, edition),
+            Self::Cycles(command) => command.run(krate, db, edition),
+            Self::Metrics(command) => command.run(krate, db, edition),
+            Self::Diff(command) => command.run(krate, db, edition),
+            Self::Api(command) => command.run(krate, db, edition),
+            Self::Calls(command) => command.run(krate, db, edition),
         })
     }
 
@@ -84,6 +176,13 @@ impl Command {
             Self::Structure(command) => &command.options.general,
             Self::Dependencies(command) => &command.options.general,
             Self::Orphans(command) => &command.options.general,
+            Self::Html(command) => &command.options.general,
+            Self::Check(command) => &command.options.general,
+            Self::Cycles(command) => &command.options.general,
+            Self::Metrics(command) => &command.options.general,
+            Self::Diff(command) => &command.options.general,
+            Self::Api(command) => &command.options.general,
+            Self::Calls(command) => &command.options.general,
         }
     }
 
@@ -93,6 +192,13 @@ impl Command {
             Self::Structure(command) => &command.options.project,
             Self::Dependencies(command) => &command.options.project,
             Self::Orphans(command) => &command.options.project,
+            Self::Html(command) => &command.options.project,
+            Self::Check(command) => &command.options.project,
+            Self::Cycles(command) => &command.options.project,
+            Self::Metrics(command) => &command.options.project,
+            Self::Diff(command) => &command.options.project,
+            Self::Api(command) => &command.options.project,
+            Self::Calls(command) => &command.options.project,
         }
     }
 
@@ -102,6 +208,13 @@ impl Command {
             Self::Structure(command) => command.load_options(),
             Self::Dependencies(command) => command.load_options(),
             Self::Orphans(command) => command.load_options(),
+            Self::Html(command) => command.load_options(),
+            Self::Check(command) => command.load_options(),
+            Self::Cycles(command) => command.load_options(),
+            Self::Metrics(command) => command.load_options(),
+            Self::Diff(command) => command.load_options(),
+            Self::Api(command) => command.load_options(),
+            Self::Calls(command) => command.load_options(),
         }
     }
 }
@@ -0,0 +1,180 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Collector for the items that are reachable from outside of a crate.
+
+use std::collections::{HashSet, VecDeque};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir, HasVisibility as _};
+use ra_ap_ide::Edition;
+
+use crate::{analyzer, item::Item};
+
+/// An item that downstream users can name, via its public path.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ApiItem {
+    /// The path via which the item is reachable from outside of the crate.
+    pub path: String,
+    pub kind: String,
+    /// The path of the item's declaration.
+    pub declared_path: String,
+}
+
+impl ApiItem {
+    pub fn is_reexport(&self) -> bool {
+        self.path != self.declared_path
+    }
+}
+
+pub(crate) struct Collector<'a> {
+    krate: hir::Crate,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> Collector<'a> {
+    pub fn new(krate: hir::Crate, db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self { krate, db, edition }
+    }
+
+    /// Collects the crate's public items, sorted by their public paths.
+    ///
+    /// Modules get walked breadth-first, starting from the crate's root module,
+    /// so the items of a module that is reachable via multiple paths
+    /// are listed under the module's shortest path.
+    pub fn collect(&self) -> Vec<ApiItem> {
+        let root_module = self.krate.root_module(self.db);
+
+        let mut items: Vec<ApiItem> = vec![];
+
+        let mut visited: HashSet<hir::Module> = HashSet::from([root_module]);
+        let mut queue: VecDeque<(hir::Module, String)> =
+            VecDeque::from([(root_module, analyzer::crate_name(self.krate, self.db))]);
+
+        while let Some((module, module_path)) = queue.pop_front() {
+            for (name, module_def_hir) in analyzer::public_scope(module, self.db, self.edition) {
+                let path = format!("{module_path}::{name}");
+
+                let item = Item::new(module_def_hir);
+                let declared_path = item.display_path(self.db, self.edition);
+
+                // Members of other crates' items are part of their own crate's API:
+                if analyzer::krate(module_def_hir, self.db) == Some(self.krate) {
+                    items.extend(self.collect_members(module_def_hir, &path, &declared_path));
+                }
+
+                items.push(ApiItem {
+                    path: path.clone(),
+                    kind: item.kind_display_name(self.db, self.edition).to_string(),
+                    declared_path,
+                });
+
+                // Modules of other crates are part of their own crate's API:
+                if let hir::ModuleDef::Module(submodule) = module_def_hir
+                    && submodule.krate(self.db) == self.krate
+                    && visited.insert(submodule)
+                {
+                    queue.push_back((submodule, path));
+                }
+            }
+        }
+
+        items.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path));
+
+        items
+    }
+
+    /// Collects the public members (i.e. variants, fields and associated items)
+    /// of the item that is reachable via `path`.
+    ///
+    /// Only inherent impls contribute associated items, as those of trait impls
+    /// are reachable via their traits, rather than via the implementing types.
+    fn collect_members(
+        &self,
+        module_def_hir: hir::ModuleDef,
+        path: &str,
+        declared_path: &str,
+    ) -> Vec<ApiItem> {
+        let mut members: Vec<ApiItem> = vec![];
+
+        let mut push_field = |field_hir: hir::Field| {
+            if field_hir.visibility(self.db) != hir::Visibility::Public {
+                return;
+            }
+
+            let name = field_hir
+                .name(self.db)
+                .display(self.db, self.edition)
+                .to_string();
+
+            members.push(ApiItem {
+                path: format!("{path}::{name}"),
+                kind: "field".to_owned(),
+                declared_path: format!("{declared_path}::{name}"),
+            });
+        };
+
+        let assoc_items: Vec<hir::AssocItem> = match module_def_hir {
+            hir::ModuleDef::Adt(adt_hir) => {
+                match adt_hir {
+                    hir::Adt::Struct(struct_hir) => struct_hir
+                        .fields(self.db)
+                        .into_iter()
+                        .for_each(&mut push_field),
+                    hir::Adt::Union(union_hir) => union_hir
+                        .fields(self.db)
+                        .into_iter()
+                        .for_each(&mut push_field),
+                    hir::Adt::Enum(enum_hir) => {
+                        for variant_hir in enum_hir.variants(self.db) {
+                            members.push(self.member(
+                                hir::ModuleDef::EnumVariant(variant_hir),
+                                path,
+                                declared_path,
+                            ));
+                        }
+                    }
+                }
+
+                hir::Impl::all_for_type(self.db, adt_hir.ty(self.db))
+                    .into_iter()
+                    .filter(|impl_hir| impl_hir.trait_(self.db).is_none())
+                    .flat_map(|impl_hir| impl_hir.items(self.db))
+                    .filter(|assoc_item_hir| {
+                        assoc_item_hir.visibility(self.db) == hir::Visibility::Public
+                    })
+                    .collect()
+            }
+            // Trait items are as visible as their traits:
+            hir::ModuleDef::Trait(trait_hir) => trait_hir.items(self.db),
+            _ => vec![],
+        };
+
+        for assoc_item_hir in assoc_items {
+            let member_def_hir = match assoc_item_hir {
+                hir::AssocItem::Function(function_hir) => hir::ModuleDef::Function(function_hir),
+                hir::AssocItem::Const(const_hir) => hir::ModuleDef::Const(const_hir),
+                hir::AssocItem::TypeAlias(type_alias_hir) => {
+                    hir::ModuleDef::TypeAlias(type_alias_hir)
+                }
+            };
+
+            members.push(self.member(member_def_hir, path, declared_path));
+        }
+
+        members
+    }
+
+    fn member(&self, member_def_hir: hir::ModuleDef, path: &str, declared_path: &str) -> ApiItem {
+        let item = Item::new(member_def_hir);
+        let name = item.display_name(self.db, self.edition);
+
+        ApiItem {
+            path: format!("{path}::{name}"),
+            kind: item.kind_display_name(self.db, self.edition).to_string(),
+            declared_path: format!("{declared_path}::{name}"),
+        }
+    }
+}
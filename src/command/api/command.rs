@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use clap::Parser;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use crate::analyzer::{self, LoadOptions};
+
+use super::{
+    collector::Collector,
+    options::{Options, OutputFormat},
+    printer::{JsonPrinter, TextPrinter},
+};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        tracing::trace!("Collecting public items ...");
+
+        let collector = Collector::new(krate, db, edition);
+        let items = collector.collect();
+
+        let crate_name = analyzer::crate_name(krate, db);
+
+        let mut stdout = std::io::stdout();
+
+        match self.options.format {
+            OutputFormat::Text => {
+                let printer = TextPrinter::new(&crate_name);
+                printer.fmt(&mut stdout, &items[..])?;
+            }
+            OutputFormat::Json => {
+                let printer = JsonPrinter::new(&crate_name);
+                printer.fmt(&mut stdout, &items[..])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printers for displaying a crate's public API surface.
+
+mod json;
+mod text;
+
+pub(crate) use self::{json::JsonPrinter, text::TextPrinter};
@@ -0,0 +1,43 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying a crate's public API surface as JSON.
+
+use json::JsonValue;
+
+use crate::command::api::collector::ApiItem;
+
+const INDENTATION: u16 = 4;
+
+pub struct JsonPrinter<'a> {
+    crate_name: &'a str,
+}
+
+impl<'a> JsonPrinter<'a> {
+    pub fn new(crate_name: &'a str) -> Self {
+        Self { crate_name }
+    }
+
+    pub fn fmt(&self, f: &mut dyn std::io::Write, items: &[ApiItem]) -> Result<(), anyhow::Error> {
+        let items: Vec<JsonValue> = items
+            .iter()
+            .map(|item| {
+                json::object! {
+                    "path": item.path.clone(),
+                    "kind": item.kind.clone(),
+                    "declared_path": item.declared_path.clone(),
+                }
+            })
+            .collect();
+
+        let root = json::object! {
+            "crate": self.crate_name,
+            "items": items,
+        };
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+}
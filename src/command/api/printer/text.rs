@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying a crate's public API surface as human-readable text.
+
+use yansi::Paint as _;
+
+use crate::command::api::{collector::ApiItem, theme::styles};
+
+pub struct TextPrinter<'a> {
+    crate_name: &'a str,
+}
+
+impl<'a> TextPrinter<'a> {
+    pub fn new(crate_name: &'a str) -> Self {
+        Self { crate_name }
+    }
+
+    pub fn fmt(&self, f: &mut dyn std::io::Write, items: &[ApiItem]) -> Result<(), anyhow::Error> {
+        let styles = styles();
+
+        writeln!(f)?;
+
+        for item in items {
+            write!(
+                f,
+                "{kind} {path}",
+                kind = item.kind.paint(styles.kind),
+                path = item.path.paint(styles.path),
+            )?;
+
+            if item.is_reexport() {
+                let reexport = format!("(re-export of `{}`)", item.declared_path);
+                write!(f, " {}", reexport.paint(styles.reexport))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Found {count} public items in crate '{crate_name}'.",
+            count = items.len(),
+            crate_name = self.crate_name,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashSet;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use clap::Parser;
+use petgraph::{
+    Direction,
+    graph::NodeIndex,
+    visit::{EdgeRef, IntoNodeReferences},
+};
+
+use crate::{
+    analyzer::LoadOptions,
+    command::dependencies::{
+        filter::Filter,
+        options::{
+            Granularity, LayoutAlgorithm, Options as DependenciesOptions, OutputFormat,
+            SelectionOptions as DependenciesSelectionOptions,
+        },
+        printer::DotPrinter,
+    },
+    graph::{BuildOptions, Edge, Graph, GraphBuilder, Node},
+};
+
+use super::options::Options;
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        tracing::trace!("Building graph ...");
+
+        let build_options = BuildOptions {
+            body_deps: false,
+            calls: true,
+        };
+
+        let builder = GraphBuilder::new(db, edition, krate, build_options);
+        let (mut graph, crate_node_idx) = builder.build()?;
+
+        // Other dependencies would otherwise pull in unrelated nodes when focusing on a function:
+        graph.retain_edges(|graph, edge_idx| matches!(graph[edge_idx], Edge::Owns | Edge::Calls));
+
+        if self.options.layout == LayoutAlgorithm::None {
+            return Ok(());
+        }
+
+        tracing::trace!("Filtering graph ...");
+
+        let dependencies_options = self.dependencies_options();
+
+        let filter = Filter::new(
+            &dependencies_options,
+            db,
+            edition,
+            std::slice::from_ref(&krate),
+        );
+        let mut graph = filter.filter(&graph, crate_node_idx)?;
+
+        Self::retain_callers_and_callees(&mut graph, crate_node_idx);
+
+        tracing::trace!("Printing graph ...");
+
+        let mut string = String::new();
+
+        let printer = DotPrinter::new(
+            &dependencies_options,
+            std::slice::from_ref(&krate),
+            db,
+            edition,
+        );
+        printer.fmt(&mut string, &graph, crate_node_idx)?;
+
+        print!("{string}");
+
+        Ok(())
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: !(self.options.selection.no_externs || self.options.selection.no_sysroot),
+            workspace: false,
+            all_targets: false,
+        }
+    }
+
+    /// Returns the options for filtering and printing the call graph.
+    fn dependencies_options(&self) -> DependenciesOptions {
+        DependenciesOptions {
+            general: self.options.general.clone(),
+            project: self.options.project.clone(),
+            selection: DependenciesSelectionOptions {
+                no_externs: self.options.selection.no_externs,
+                no_fns: false,
+                no_impls: false,
+                no_modules: false,
+                no_owns: self.options.selection.no_owns,
+                no_sysroot: self.options.selection.no_sysroot,
+                no_traits: false,
+                no_types: false,
+                no_uses: false,
+                no_private: false,
+                no_pub_crate: false,
+                no_pub_module: vec![],
+                no_pub_modules: false,
+                no_pub_super: false,
+            },
+            acyclic: false,
+            granularity: Granularity::Items,
+            layout: self.options.layout,
+            splines: self.options.splines,
+            format: OutputFormat::Dot,
+            output: None,
+            focus_on: self.options.focus_on.clone(),
+            max_depth: self.options.max_depth,
+            cfg_test: self.options.cfg_test,
+            body_deps: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+
+    /// Drops the nodes that are neither functions, nor (transitively) own any,
+    /// with the exception of the crate's node, as well as any "calls" edges
+    /// that got redirected to a non-function by filtering out their caller or callee.
+    fn retain_callers_and_callees(graph: &mut Graph<Node, Edge>, root_idx: NodeIndex) {
+        let is_function = |node: &Node| matches!(node.hir, hir::ModuleDef::Function(_));
+
+        graph.retain_edges(|graph, edge_idx| {
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+            graph[edge_idx] != Edge::Calls
+                || (is_function(&graph[source_idx]) && is_function(&graph[target_idx]))
+        });
+
+        let mut nodes_to_keep: HashSet<NodeIndex> = HashSet::from([root_idx]);
+
+        let mut stack: Vec<NodeIndex> = graph
+            .node_references()
+            .filter(|(_, node)| is_function(node))
+            .map(|(node_idx, _)| node_idx)
+            .collect();
+
+        while let Some(node_idx) = stack.pop() {
+            if !nodes_to_keep.insert(node_idx) {
+                continue;
+            }
+
+            let owners = graph
+                .edges_directed(node_idx, Direction::Incoming)
+                .filter(|edge_ref| matches!(edge_ref.weight(), Edge::Owns))
+                .map(|edge_ref| edge_ref.source());
+
+            stack.extend(owners);
+        }
+
+        graph.retain_nodes(|_graph, node_idx| nodes_to_keep.contains(&node_idx));
+    }
+}
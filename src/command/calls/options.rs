@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use clap::Parser;
+
+use crate::{
+    command::dependencies::options::{LayoutAlgorithm, SplinesType},
+    options::{GeneralOptions, ProjectOptions},
+};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[group(id = "CallsOptions")]
+pub struct Options {
+    #[command(flatten)]
+    pub general: GeneralOptions,
+
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    #[command(flatten)]
+    pub selection: SelectionOptions,
+
+    /// The graph layout algorithm to use
+    /// (e.g. none, dot, neato, twopi, circo, fdp, sfdp).
+    #[arg(long = "layout", default_value = "neato")]
+    pub layout: LayoutAlgorithm,
+
+    /// The different types to draw lines between nodes
+    /// (e.g. none, line, spline, ortho).
+    #[arg(long = "splines", default_value = "line")]
+    pub splines: SplinesType,
+
+    /// Focus the graph on a particular function's callers and callees,
+    /// e.g. "foo::bar::baz".
+    #[arg(long = "focus-on")]
+    pub focus_on: Option<String>,
+
+    /// The maximum depth of the generated graph
+    /// relative to the crate's root node, or nodes selected by '--focus-on'.
+    #[arg(long = "max-depth")]
+    pub max_depth: Option<usize>,
+
+    /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
+    #[arg(long = "cfg-test")]
+    pub cfg_test: bool,
+}
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[group(id = "CallsSelectionOptions")]
+pub struct SelectionOptions {
+    /// Filter out functions from extern crates from graph.
+    #[arg(long = "no-externs")]
+    pub no_externs: bool,
+
+    /// Filter out structural "owns" edges from graph.
+    #[arg(long = "no-owns")]
+    pub no_owns: bool,
+
+    /// Filter out functions from sysroot crates (`std`, `core` & friends) from graph.
+    #[arg(long = "no-sysroot")]
+    pub no_sysroot: bool,
+}
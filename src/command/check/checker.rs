@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Checker for finding a crate's violations of its rules.
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use crate::{
+    analyzer,
+    graph::{Edge, Graph, Node},
+};
+
+use super::config::{Config, Rule};
+
+/// A "uses" dependency that violates one of the rules.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(crate) struct Violation {
+    /// The path of the item using the denied item.
+    pub source: String,
+    /// The path of the denied item.
+    pub target: String,
+    /// The index of the violated rule.
+    pub rule: usize,
+    /// The (as declared) denied path that matched the target.
+    pub denied: String,
+}
+
+pub(crate) struct Checker<'a> {
+    config: &'a Config,
+    crate_name: String,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> Checker<'a> {
+    pub fn new(
+        config: &'a Config,
+        krate: hir::Crate,
+        db: &'a dyn HirDatabase,
+        edition: Edition,
+    ) -> Self {
+        let crate_name = analyzer::crate_name(krate, db);
+
+        Self {
+            config,
+            crate_name,
+            db,
+            edition,
+        }
+    }
+
+    pub fn check(&self, graph: &Graph<Node, Edge>) -> Vec<Violation> {
+        let mut violations = vec![];
+
+        for edge_idx in graph.edge_indices() {
+            if !graph[edge_idx].is_dependency() {
+                continue;
+            }
+
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+            let source = graph[source_idx].display_path(self.db, self.edition);
+            let target = graph[target_idx].display_path(self.db, self.edition);
+
+            for (index, rule) in self.config.rules.iter().enumerate() {
+                if let Some(denied) = self.violated_path(rule, &source, &target) {
+                    violations.push(Violation {
+                        source: source.clone(),
+                        target: target.clone(),
+                        rule: index,
+                        denied: denied.to_owned(),
+                    });
+                }
+            }
+        }
+
+        violations.sort();
+        violations.dedup();
+
+        violations
+    }
+
+    /// Returns the denied path that `source` using `target` violates, if any.
+    fn violated_path<'r>(&self, rule: &'r Rule, source: &str, target: &str) -> Option<&'r str> {
+        if !self.is_within(source, &rule.from) {
+            return None;
+        }
+
+        rule.deny
+            .iter()
+            .find(|denied| {
+                // Items within a denied path are free to use each other:
+                self.is_within(target, denied) && !self.is_within(source, denied)
+            })
+            .map(String::as_str)
+    }
+
+    /// Returns `true` if `path` is `prefix`, or an item nested within it.
+    fn is_within(&self, path: &str, prefix: &str) -> bool {
+        let prefix = self.resolve(prefix);
+
+        match path.strip_prefix(prefix.as_str()) {
+            Some(suffix) => suffix.is_empty() || suffix.starts_with("::"),
+            None => false,
+        }
+    }
+
+    /// Resolves a leading `crate` of a rule's path to the crate's name.
+    fn resolve(&self, path: &str) -> String {
+        match path.split_once("::") {
+            Some(("crate", suffix)) => format!("{}::{suffix}", self.crate_name),
+            None if path == "crate" => self.crate_name.clone(),
+            _ => path.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use clap::Parser;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use crate::{
+    analyzer::{self, LoadOptions},
+    graph::{BuildOptions, GraphBuilder},
+};
+
+use super::{checker::Checker, config::Config, options::Options, printer::TextPrinter};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        self.config().map(|_| ())
+    }
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        let config = self.config()?;
+
+        tracing::trace!("Building graph ...");
+
+        let builder = GraphBuilder::new(db, edition, krate, BuildOptions::default());
+        let (graph, _crate_node_idx) = builder.build()?;
+
+        tracing::trace!("Checking graph ...");
+
+        let checker = Checker::new(&config, krate, db, edition);
+        let violations = checker.check(&graph);
+
+        let mut stdout = std::io::stdout();
+
+        let printer = TextPrinter::new(&config);
+        printer.fmt(&mut stdout, &violations[..])?;
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            let count = violations.len();
+            let crate_name = analyzer::crate_name(krate, db);
+            Err(anyhow::anyhow!(
+                "Found {count} rule violations in crate '{crate_name}'"
+            ))
+        }
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+
+    fn config(&self) -> anyhow::Result<Config> {
+        let path = Config::path(self.options.config.as_deref(), &self.options.project);
+        Config::load(&path)
+    }
+}
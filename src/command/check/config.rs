@@ -0,0 +1,150 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Configuration of the rules to check a crate against.
+//!
+//! Rules are declared in a TOML file, as an array of tables:
+//!
+//! ```toml
+//! [[rules]]
+//! from = "crate::domain"
+//! deny = ["crate::infra"]
+//! reason = "The domain must not depend on infrastructure."
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, anyhow, bail};
+
+use crate::options::ProjectOptions;
+
+pub(crate) const DEFAULT_CONFIG_FILE_NAME: &str = "cargo-modules.toml";
+
+/// A rule denying items within one path from using items within other paths.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Rule {
+    /// The path whose items the rule applies to.
+    pub from: String,
+    /// The paths whose items must not be used.
+    pub deny: Vec<String>,
+    /// An optional explanation of the rule.
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Config {
+    pub rules: Vec<Rule>,
+}
+
+impl Config {
+    /// Returns the path of the config file, falling back to
+    /// the default file next to the project's manifest.
+    pub fn path(config: Option<&Path>, project_options: &ProjectOptions) -> PathBuf {
+        if let Some(path) = config {
+            return path.to_owned();
+        }
+
+        let manifest_path = project_options.manifest_path.as_path();
+        let manifest_dir = if manifest_path.is_file() {
+            manifest_path.parent().expect("parent directory")
+        } else {
+            manifest_path
+        };
+
+        manifest_dir.join(DEFAULT_CONFIG_FILE_NAME)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+        Self::parse(&contents)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        let table: toml::Table = contents.parse()?;
+
+        for key in table.keys() {
+            if key != "rules" {
+                bail!("Unknown key `{key}`");
+            }
+        }
+
+        let Some(rules) = table.get("rules") else {
+            return Ok(Self { rules: vec![] });
+        };
+
+        let rules = rules
+            .as_array()
+            .ok_or_else(|| anyhow!("Expected `rules` to be an array of tables"))?
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                let rule = rule
+                    .as_table()
+                    .ok_or_else(|| anyhow!("Expected `rules` to be an array of tables"))?;
+
+                Rule::parse(rule).with_context(|| format!("Invalid rule #{}", index + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self { rules })
+    }
+}
+
+impl Rule {
+    fn parse(table: &toml::Table) -> anyhow::Result<Self> {
+        for key in table.keys() {
+            if !["from", "deny", "reason"].contains(&key.as_str()) {
+                bail!("Unknown key `{key}`");
+            }
+        }
+
+        let from = table
+            .get("from")
+            .ok_or_else(|| anyhow!("Missing key `from`"))?
+            .as_str()
+            .ok_or_else(|| anyhow!("Expected `from` to be a string"))?;
+
+        let deny: Vec<&str> = match table.get("deny") {
+            Some(toml::Value::String(path)) => vec![path.as_str()],
+            Some(toml::Value::Array(paths)) => paths
+                .iter()
+                .map(|path| {
+                    path.as_str()
+                        .ok_or_else(|| anyhow!("Expected `deny` to be an array of strings"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            Some(_) => bail!("Expected `deny` to be a string or an array of strings"),
+            None => bail!("Missing key `deny`"),
+        };
+
+        if deny.is_empty() {
+            bail!("Expected `deny` to contain at least one path");
+        }
+
+        let reason = match table.get("reason") {
+            Some(reason) => Some(
+                reason
+                    .as_str()
+                    .ok_or_else(|| anyhow!("Expected `reason` to be a string"))?
+                    .to_owned(),
+            ),
+            None => None,
+        };
+
+        for path in std::iter::once(&from).chain(deny.iter()) {
+            if path.is_empty() || path.split("::").any(str::is_empty) {
+                bail!("Invalid path `{path}`");
+            }
+        }
+
+        Ok(Self {
+            from: from.to_owned(),
+            deny: deny.into_iter().map(str::to_owned).collect(),
+            reason,
+        })
+    }
+}
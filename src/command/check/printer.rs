@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying rule violations as human-readable text.
+
+use yansi::Paint as _;
+
+use super::{checker::Violation, config::Config, theme::styles};
+
+pub struct TextPrinter<'a> {
+    config: &'a Config,
+}
+
+impl<'a> TextPrinter<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self { config }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn std::io::Write,
+        violations: &[Violation],
+    ) -> Result<(), anyhow::Error> {
+        let styles = styles();
+
+        if violations.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{}", "No violations found.".paint(styles.success))?;
+
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{count} violations found:", count = violations.len())?;
+        writeln!(f)?;
+
+        for violation in violations {
+            let rule = &self.config.rules[violation.rule];
+
+            writeln!(
+                f,
+                "{error}: `{source}` uses `{target}`",
+                error = "error".paint(styles.error),
+                source = violation.source,
+                target = violation.target,
+            )?;
+
+            writeln!(
+                f,
+                "  {arrow} rule #{number}: `{from}` must not use `{denied}`",
+                arrow = "-->".paint(styles.chrome),
+                number = violation.rule + 1,
+                from = rule.from,
+                denied = violation.denied,
+            )?;
+
+            if let Some(reason) = &rule.reason {
+                writeln!(f, "   {pipe}", pipe = "|".paint(styles.chrome))?;
+                writeln!(
+                    f,
+                    "   {eq} {help}: {reason}",
+                    eq = "=".paint(styles.chrome),
+                    help = "help".paint(styles.help),
+                )?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use clap::Parser;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use crate::{
+    analyzer::{self, LoadOptions},
+    command::dependencies::collapser::Collapser,
+    graph::{BuildOptions, GraphBuilder},
+};
+
+use super::{finder::CycleFinder, options::Options, printer::TextPrinter};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        tracing::trace!("Building graph ...");
+
+        let builder = GraphBuilder::new(db, edition, krate, BuildOptions::default());
+        let (graph, crate_node_idx) = builder.build()?;
+
+        tracing::trace!("Collapsing graph ...");
+
+        let collapser = Collapser::new(self.options.granularity, db);
+        let (graph, _crate_node_idx) = collapser.collapse(&graph, crate_node_idx);
+
+        tracing::trace!("Searching for cycles ...");
+
+        let finder = CycleFinder::new(db, edition);
+        let cycles = finder.find(&graph);
+
+        let mut stdout = std::io::stdout();
+
+        let printer = TextPrinter::new(db, edition);
+        printer.fmt(&mut stdout, &graph, &cycles[..])?;
+
+        if cycles.is_empty() {
+            Ok(())
+        } else {
+            let count = cycles.len();
+            let crate_name = analyzer::crate_name(krate, db);
+            Err(anyhow::anyhow!(
+                "Found {count} dependency cycles in crate '{crate_name}'"
+            ))
+        }
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Finder for detecting all of a crate's dependency cycles.
+
+use std::{collections::HashSet, ops::ControlFlow};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::{algo::tarjan_scc, graph::NodeIndex};
+
+use crate::{
+    command::dependencies::cycles::tri_color::{
+        NodeStatus, TriColorDepthFirstSearch, TriColorVisitor,
+    },
+    graph::{Edge, Graph, Node},
+};
+
+/// A strongly connected component of "uses" edges.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Cycle {
+    /// The component's nodes, sorted by path.
+    pub component: Vec<NodeIndex>,
+    /// A cycle through (some of) the component's nodes.
+    pub cycle: Vec<NodeIndex>,
+}
+
+pub(crate) struct CycleFinder<'a> {
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> CycleFinder<'a> {
+    pub fn new(db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self { db, edition }
+    }
+
+    /// Returns the graph's cycles, ranked by the size of their components (largest first).
+    pub fn find(&self, graph: &Graph<Node, Edge>) -> Vec<Cycle> {
+        // Only dependency edges can form cycles, since "owns" edges form a tree:
+        let mut graph = graph.clone();
+        graph.retain_edges(|graph, edge_idx| graph[edge_idx].is_dependency());
+
+        let mut cycles: Vec<(Vec<String>, Cycle)> = tarjan_scc(&graph)
+            .into_iter()
+            // Self-referential items (e.g. recursive functions) are not considered cycles:
+            .filter(|component| component.len() > 1)
+            .map(|component| {
+                let mut component: Vec<(String, NodeIndex)> = component
+                    .into_iter()
+                    .map(|node_idx| {
+                        (
+                            graph[node_idx].display_path(self.db, self.edition),
+                            node_idx,
+                        )
+                    })
+                    .collect();
+
+                component.sort();
+
+                let (paths, component): (Vec<String>, Vec<NodeIndex>) =
+                    component.into_iter().unzip();
+
+                let cycle = Self::find_cycle(&graph, &component);
+
+                (paths, Cycle { component, cycle })
+            })
+            .collect();
+
+        cycles.sort_by(|(lhs_paths, _), (rhs_paths, _)| {
+            rhs_paths
+                .len()
+                .cmp(&lhs_paths.len())
+                .then_with(|| lhs_paths.cmp(rhs_paths))
+        });
+
+        cycles.into_iter().map(|(_, cycle)| cycle).collect()
+    }
+
+    fn find_cycle(graph: &Graph<Node, Edge>, component: &[NodeIndex]) -> Vec<NodeIndex> {
+        let mut detector = ComponentCycleDetector {
+            component: component.iter().copied().collect(),
+        };
+
+        // Every node of a strongly connected component lies on a cycle within it:
+        let mut cycle = TriColorDepthFirstSearch::new(graph)
+            .run_from(component[0], &mut detector)
+            .expect("strongly connected component should contain a cycle");
+
+        // The search returns the cycle's nodes against the direction of its edges:
+        cycle.reverse();
+
+        // Start the cycle at its first node in path-sorted order, for deterministic output:
+        let position = |node_idx: &NodeIndex| component.iter().position(|idx| idx == node_idx);
+        let start = (0..cycle.len())
+            .min_by_key(|index| position(&cycle[*index]))
+            .unwrap_or(0);
+        cycle.rotate_left(start);
+
+        cycle
+    }
+}
+
+/// Like `CycleDetector`, but confined to a single strongly connected component.
+struct ComponentCycleDetector {
+    component: HashSet<NodeIndex>,
+}
+
+impl<G> TriColorVisitor<G> for ComponentCycleDetector {
+    type BreakVal = ();
+
+    fn node_examined(
+        &mut self,
+        _node: NodeIndex,
+        prior_status: Option<NodeStatus>,
+    ) -> ControlFlow<Self::BreakVal> {
+        match prior_status {
+            Some(NodeStatus::Visited) => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+
+    fn ignore_edge(&mut self, source: NodeIndex, target: NodeIndex) -> bool {
+        source == target || !self.component.contains(&target)
+    }
+}
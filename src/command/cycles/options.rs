@@ -0,0 +1,30 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use clap::Parser;
+
+use crate::{
+    command::dependencies::options::Granularity,
+    options::{GeneralOptions, ProjectOptions},
+};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[group(id = "CyclesOptions")]
+pub struct Options {
+    #[command(flatten)]
+    pub general: GeneralOptions,
+
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// The granularity at which to search for cycles,
+    /// collapsing items into their owning types or modules
+    /// (e.g. items, types, modules, top-level-modules).
+    #[arg(long = "granularity", default_value = "items")]
+    pub granularity: Granularity,
+
+    /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
+    #[arg(long = "cfg-test")]
+    pub cfg_test: bool,
+}
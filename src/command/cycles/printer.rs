@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying dependency cycles as human-readable text.
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use crate::{
+    command::dependencies::cycles::draw_cycle,
+    graph::{Edge, Graph, Node},
+};
+
+use super::finder::Cycle;
+
+pub struct TextPrinter<'a> {
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> TextPrinter<'a> {
+    pub fn new(db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self { db, edition }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn std::io::Write,
+        graph: &Graph<Node, Edge>,
+        cycles: &[Cycle],
+    ) -> Result<(), anyhow::Error> {
+        if cycles.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "No cycles found.")?;
+
+            return Ok(());
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{count} cycles found:", count = cycles.len())?;
+        writeln!(f)?;
+
+        for (index, cycle) in cycles.iter().enumerate() {
+            writeln!(
+                f,
+                "cycle #{number} between {count} items:",
+                number = index + 1,
+                count = cycle.component.len()
+            )?;
+            writeln!(f)?;
+
+            let drawing = draw_cycle(graph, cycle.cycle.clone(), self.db, self.edition);
+            writeln!(f, "{drawing}")?;
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Summary (ranked by component size):")?;
+        writeln!(f)?;
+
+        for (index, cycle) in cycles.iter().enumerate() {
+            let paths: Vec<String> = cycle
+                .component
+                .iter()
+                .map(|node_idx| {
+                    format!("`{}`", graph[*node_idx].display_path(self.db, self.edition))
+                })
+                .collect();
+
+            writeln!(
+                f,
+                "  #{number}: {count} items: {paths}",
+                number = index + 1,
+                count = cycle.component.len(),
+                paths = paths.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,8 +4,10 @@
 
 pub mod options;
 
+pub(crate) mod collapser;
 pub(crate) mod command;
-pub(super) mod cycles;
+pub(crate) mod cycles;
 pub(super) mod filter;
 pub(super) mod printer;
+pub(super) mod renderer;
 pub(super) mod theme;
@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Collapser for coarsening a crate's graph along its "owns" edges.
+
+use std::collections::{HashMap, HashSet};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    analyzer,
+    graph::{Edge, Graph, Node},
+};
+
+use super::options::Granularity;
+
+pub(crate) struct Collapser<'a> {
+    granularity: Granularity,
+    db: &'a dyn HirDatabase,
+}
+
+impl<'a> Collapser<'a> {
+    pub fn new(granularity: Granularity, db: &'a dyn HirDatabase) -> Self {
+        Self { granularity, db }
+    }
+
+    /// Collapses each node into its closest owner of the chosen granularity,
+    /// dropping any "uses" (or "re-exports", "implements") edges between nodes that got collapsed into the same owner.
+    ///
+    /// Returns the collapsed graph, as well as the index of the root node within it.
+    pub fn collapse(
+        &self,
+        graph: &Graph<Node, Edge>,
+        root_idx: NodeIndex,
+    ) -> (Graph<Node, Edge>, NodeIndex) {
+        let mut parents: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+
+        for edge_idx in graph.edge_indices() {
+            if graph[edge_idx] != Edge::Owns {
+                continue;
+            }
+
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+            parents.entry(target_idx).or_insert(source_idx);
+        }
+
+        let owners: HashMap<NodeIndex, NodeIndex> = graph
+            .node_indices()
+            .map(|node_idx| (node_idx, self.owner(graph, &parents, node_idx)))
+            .collect();
+
+        // Build a fresh graph, rather than removing nodes from a clone,
+        // so that the collapsed graph's node indices remain contiguous:
+        let mut collapsed = Graph::default();
+        let mut indices: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+
+        for node_idx in graph.node_indices() {
+            if owners[&node_idx] == node_idx {
+                indices.insert(node_idx, collapsed.add_node(graph[node_idx].clone()));
+            }
+        }
+
+        let mut edges: HashSet<(NodeIndex, NodeIndex, Edge)> = HashSet::new();
+
+        for edge_idx in graph.edge_indices() {
+            let edge = graph[edge_idx];
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+            let (source_idx, target_idx) = match edge {
+                Edge::Uses | Edge::Reexports | Edge::Implements | Edge::Calls => {
+                    (owners[&source_idx], owners[&target_idx])
+                }
+                Edge::Owns => (source_idx, target_idx),
+            };
+
+            // Dependencies within an owner (e.g. intra-module recursion) are collapsed away:
+            if source_idx == target_idx {
+                continue;
+            }
+
+            let (Some(&source_idx), Some(&target_idx)) =
+                (indices.get(&source_idx), indices.get(&target_idx))
+            else {
+                continue;
+            };
+
+            if edges.insert((source_idx, target_idx, edge)) {
+                collapsed.add_edge(source_idx, target_idx, edge);
+            }
+        }
+
+        (collapsed, indices[&root_idx])
+    }
+
+    /// Returns the node that `node_idx` gets collapsed into.
+    fn owner(
+        &self,
+        graph: &Graph<Node, Edge>,
+        parents: &HashMap<NodeIndex, NodeIndex>,
+        node_idx: NodeIndex,
+    ) -> NodeIndex {
+        let mut ancestors =
+            std::iter::successors(Some(node_idx), |node_idx| parents.get(node_idx).copied());
+
+        match self.granularity {
+            Granularity::Items => node_idx,
+            Granularity::Types => ancestors
+                .find(|node_idx| {
+                    matches!(
+                        graph[*node_idx].hir,
+                        hir::ModuleDef::Adt(_) | hir::ModuleDef::Trait(_)
+                    )
+                })
+                .unwrap_or(node_idx),
+            Granularity::Modules => ancestors
+                .find(|node_idx| matches!(graph[*node_idx].hir, hir::ModuleDef::Module(_)))
+                .unwrap_or(node_idx),
+            Granularity::TopLevelModules => {
+                let ancestors: Vec<NodeIndex> = ancestors.collect();
+
+                // Top-level modules are the modules owned by the crate's root module,
+                // which itself represents any other items it owns:
+                match ancestors.as_slice() {
+                    [.., top_level_idx, root_idx]
+                        if analyzer::moduledef_is_crate(graph[*root_idx].hir, self.db) =>
+                    {
+                        match graph[*top_level_idx].hir {
+                            hir::ModuleDef::Module(_) => *top_level_idx,
+                            _ => *root_idx,
+                        }
+                    }
+                    _ => node_idx,
+                }
+            }
+        }
+    }
+}
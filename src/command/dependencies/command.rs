@@ -6,19 +6,28 @@ use hir::db::HirDatabase;
 use ra_ap_hir::{self as hir};
 use ra_ap_ide::{self as ide};
 
+use anyhow::Context as _;
 use clap::Parser;
 use petgraph::graph::NodeIndex;
 
 use crate::{
     analyzer::LoadOptions,
-    graph::{Edge, Graph, GraphBuilder, Node},
+    command::cycles::finder::{Cycle, CycleFinder},
+    graph::{BuildOptions, Edge, Graph, GraphBuilder, Node},
 };
 
 use super::{
-    cycles::tri_color::{CycleDetector, TriColorDepthFirstSearch},
+    collapser::Collapser,
+    cycles::{
+        draw_cycle,
+        tri_color::{CycleDetector, TriColorDepthFirstSearch},
+    },
     filter::Filter,
-    options::{LayoutAlgorithm, Options},
-    printer::Printer,
+    options::{Granularity, LayoutAlgorithm, Options, OutputFormat},
+    printer::{
+        DotPrinter, GexfPrinter, GraphMlPrinter, JsonPrinter, MermaidPrinter, PlantUmlPrinter,
+    },
+    renderer::SvgRenderer,
 };
 
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
@@ -34,10 +43,18 @@ impl Command {
 
     pub(crate) fn sanitize(&mut self) {}
 
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        if let Some(path) = &self.options.output {
+            SvgRenderer::validate(&self.options, path)?;
+        }
+
+        Ok(())
+    }
+
     #[doc(hidden)]
     pub fn run(
         self,
-        krate: hir::Crate,
+        krates: &[hir::Crate],
         db: &ide::RootDatabase,
         edition: ide::Edition,
     ) -> anyhow::Result<()> {
@@ -45,18 +62,36 @@ impl Command {
 
         tracing::trace!("Building graph ...");
 
-        let builder = GraphBuilder::new(db, edition, krate);
-        let (graph, crate_node_idx) = builder.build()?;
-
-        if self.options.acyclic
-            && let Some(cycle) =
-                TriColorDepthFirstSearch::new(&graph).run_from(crate_node_idx, &mut CycleDetector)
-        {
-            assert!(cycle.len() >= 2);
-            let first = graph[cycle[0]].display_path(db, edition);
-            let last = graph[*cycle.last().unwrap()].display_path(db, edition);
-            let drawing = draw_cycle(&graph, cycle, db, edition);
-            anyhow::bail!("circular dependency between `{first}` and `{last}`.\n\n{drawing}");
+        let build_options = BuildOptions {
+            body_deps: self.options.body_deps,
+            calls: false,
+        };
+
+        let builder = GraphBuilder::with_crates(db, edition, krates.to_vec(), build_options);
+        let (graph, crate_node_idxs) = builder.build_all()?;
+
+        // The first crate's node is the graph's main root (e.g. for labeling it):
+        let crate_node_idx = crate_node_idxs[0];
+
+        if self.options.acyclic {
+            if self.options.granularity == Granularity::Items {
+                if let Some(cycle) = TriColorDepthFirstSearch::new(&graph)
+                    .run_from(crate_node_idx, &mut CycleDetector)
+                {
+                    return Err(Self::cycle_error(&graph, cycle, db, edition));
+                }
+            } else {
+                let collapser = Collapser::new(self.options.granularity, db);
+                let (graph, _crate_node_idx) = collapser.collapse(&graph, crate_node_idx);
+
+                // The finder only considers dependency edges, as a child module depending on
+                // its (collapsed) parent would otherwise form a cycle with the "owns" edge between them:
+                let finder = CycleFinder::new(db, edition);
+
+                if let Some(Cycle { cycle, .. }) = finder.find(&graph).into_iter().next() {
+                    return Err(Self::cycle_error(&graph, cycle, db, edition));
+                }
+            }
         }
 
         if self.options.layout == LayoutAlgorithm::None {
@@ -65,15 +100,51 @@ impl Command {
 
         tracing::trace!("Filtering graph ...");
 
-        let filter = Filter::new(&self.options, db, edition, krate);
-        let graph = filter.filter(&graph, crate_node_idx)?;
+        let filter = Filter::new(&self.options, db, edition, krates);
+        let graph = filter.filter_all(&graph, &crate_node_idxs)?;
+
+        if let Some(path) = &self.options.output {
+            tracing::trace!("Rendering graph ...");
+
+            let renderer = SvgRenderer::new(&self.options, krates, db, edition);
+            let svg = renderer.render(&graph)?;
+
+            std::fs::write(path, svg)
+                .with_context(|| format!("Failed to write rendered graph to {}", path.display()))?;
+
+            return Ok(());
+        }
 
         tracing::trace!("Printing graph ...");
 
         let mut string = String::new();
 
-        let printer = Printer::new(&self.options, krate, db, edition);
-        printer.fmt(&mut string, &graph, crate_node_idx)?;
+        match self.options.format {
+            OutputFormat::Dot => {
+                let printer = DotPrinter::new(&self.options, krates, db, edition);
+                printer.fmt(&mut string, &graph, crate_node_idx)?;
+            }
+            OutputFormat::Json => {
+                let printer = JsonPrinter::new(krates, db, edition);
+                printer.fmt(&mut string, &graph, crate_node_idx)?;
+            }
+            OutputFormat::Mermaid => {
+                let printer = MermaidPrinter::new(&self.options, krates, db, edition);
+                printer.fmt(&mut string, &graph, crate_node_idx)?;
+            }
+            OutputFormat::PlantUml => {
+                let printer = PlantUmlPrinter::new(krates, db, edition);
+                printer.fmt(&mut string, &graph, crate_node_idx)?;
+            }
+            OutputFormat::GraphMl => {
+                let printer = GraphMlPrinter::new(krates, db, edition);
+                printer.fmt(&mut string, &graph, crate_node_idx)?;
+            }
+            OutputFormat::Gexf => {
+                let printer = GexfPrinter::new(krates, db, edition);
+                printer.fmt(&mut string, &graph, crate_node_idx)?;
+            }
+        }
 
         print!("{string}");
 
@@ -86,27 +157,21 @@ impl Command {
             sysroot: !(self.options.selection.no_uses
                 || self.options.selection.no_externs
                 || self.options.selection.no_sysroot),
+            workspace: self.options.workspace,
+            all_targets: self.options.all_targets,
         }
     }
-}
-
-fn draw_cycle(
-    graph: &Graph<Node, Edge>,
-    cycle: Vec<NodeIndex>,
-    db: &dyn HirDatabase,
-    edition: ide::Edition,
-) -> String {
-    assert!(!cycle.is_empty());
 
-    let first = graph[cycle[0]].display_path(db, edition);
-    let mut drawing = format!("┌> {first}\n");
-
-    for (i, node) in cycle[1..].iter().enumerate() {
-        let path = graph[*node].display_path(db, edition);
-        drawing += &format!("│  {:>width$}└─> {path}\n", "", width = i * 4);
+    fn cycle_error(
+        graph: &Graph<Node, Edge>,
+        cycle: Vec<NodeIndex>,
+        db: &dyn HirDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Error {
+        assert!(cycle.len() >= 2);
+        let first = graph[cycle[0]].display_path(db, edition);
+        let last = graph[*cycle.last().unwrap()].display_path(db, edition);
+        let drawing = draw_cycle(graph, cycle, db, edition);
+        anyhow::anyhow!("circular dependency between `{first}` and `{last}`.\n\n{drawing}")
     }
-
-    drawing += &format!("└──{:─>width$}┘", "", width = (cycle.len() - 1) * 4);
-
-    drawing
 }
@@ -4,3 +4,33 @@
 
 #[rustfmt::skip]
 pub mod tri_color;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{Edge, Graph, Node};
+
+/// Draws the cycle as a chain of arrows, closing back onto its first node.
+pub(crate) fn draw_cycle(
+    graph: &Graph<Node, Edge>,
+    cycle: Vec<NodeIndex>,
+    db: &dyn HirDatabase,
+    edition: Edition,
+) -> String {
+    assert!(!cycle.is_empty());
+
+    let first = graph[cycle[0]].display_path(db, edition);
+    let mut drawing = format!("┌> {first}\n");
+
+    for (i, node) in cycle[1..].iter().enumerate() {
+        let path = graph[*node].display_path(db, edition);
+        drawing += &format!("│  {:>width$}└─> {path}\n", "", width = i * 4);
+    }
+
+    drawing += &format!("└──{:─>width$}┘", "", width = (cycle.len() - 1) * 4);
+
+    drawing
+}
@@ -13,7 +13,7 @@ use petgraph::{
     Direction,
     graph::NodeIndex,
     stable_graph::EdgeIndex,
-    visit::{Bfs, EdgeRef, IntoEdgeReferences},
+    visit::{Bfs, EdgeRef, IntoEdgeReferences, VisitMap as _},
 };
 
 use crate::{
@@ -29,20 +29,22 @@ pub struct Filter<'a> {
     options: &'a Options,
     db: &'a dyn HirDatabase,
     edition: Edition,
-    krate: hir::Crate,
+    krates: &'a [hir::Crate],
 }
 
 impl<'a> Filter<'a> {
+    /// Creates a filter for the graph of the given crates,
+    /// the first of which is the one that `crate::…` paths refer to.
     pub fn new(
         options: &'a Options,
         db: &'a dyn HirDatabase,
         edition: Edition,
-        krate: hir::Crate,
+        krates: &'a [hir::Crate],
     ) -> Self {
         Self {
             options,
             db,
-            krate,
+            krates,
             edition,
         }
     }
@@ -51,25 +53,40 @@ impl<'a> Filter<'a> {
         &self,
         graph: &Graph<Node, Edge>,
         root_idx: NodeIndex,
+    ) -> anyhow::Result<Graph<Node, Edge>> {
+        self.filter_all(graph, &[root_idx])
+    }
+
+    /// Filters a graph with multiple root nodes (e.g. one per crate of a workspace),
+    /// dropping any nodes that aren't reachable from any of them.
+    pub fn filter_all(
+        &self,
+        graph: &Graph<Node, Edge>,
+        root_idxs: &[NodeIndex],
     ) -> anyhow::Result<Graph<Node, Edge>> {
         const ROOT_DROP_ERR_MSG: &str = "Root module should not be dropped";
 
         let mut graph = graph.clone();
 
-        let crate_name = self.krate.display_name(self.db).unwrap().to_string();
+        let crate_name = self.krates[0].display_name(self.db).unwrap().to_string();
         let focus_on = self.options.focus_on.as_deref();
         let use_tree: ast::UseTree = crate::utils::sanitized_use_tree(focus_on, &crate_name)?;
 
         tracing::trace!("Searching for focus nodes in graph ...");
 
-        let focus_node_idxs: Vec<NodeIndex> = graph
-            .node_indices()
-            .filter(|node_idx| {
-                let node = &graph[*node_idx];
-                let path = node.display_path(self.db, self.edition);
-                analyzer::use_tree_matches_item_path(&use_tree, &path[..])
-            })
-            .collect();
+        let focus_node_idxs: Vec<NodeIndex> = if focus_on.is_none() && root_idxs.len() > 1 {
+            // Without an explicit focus, each of the crates is in focus:
+            root_idxs.to_vec()
+        } else {
+            graph
+                .node_indices()
+                .filter(|node_idx| {
+                    let node = &graph[*node_idx];
+                    let path = node.display_path(self.db, self.edition);
+                    analyzer::use_tree_matches_item_path(&use_tree, &path[..])
+                })
+                .collect()
+        };
 
         if focus_node_idxs.is_empty() {
             anyhow::bail!(
@@ -84,8 +101,11 @@ impl<'a> Filter<'a> {
 
         let included_visibilities = VisibilityFilter::build_from_options(self.options);
 
+        // The roots of crates that are unrelated to the focus nodes get kept regardless:
         debug_assert!(
-            nodes_within_max_depth.contains(&root_idx),
+            root_idxs
+                .iter()
+                .any(|root_idx| nodes_within_max_depth.contains(root_idx)),
             "{}",
             ROOT_DROP_ERR_MSG
         );
@@ -94,7 +114,13 @@ impl<'a> Filter<'a> {
         let mut stack: Vec<_> = {
             let mut stack: Vec<_> = Vec::default();
 
-            let mut traversal = Bfs::new(&graph, root_idx);
+            let mut traversal = Bfs::new(&graph, root_idxs[0]);
+            for root_idx in &root_idxs[1..] {
+                if traversal.discovered.visit(*root_idx) {
+                    traversal.stack.push_back(*root_idx);
+                }
+            }
+
             while let Some(node_idx) = traversal.next(&graph) {
                 stack.push(node_idx);
             }
@@ -119,8 +145,8 @@ impl<'a> Filter<'a> {
                 // Make sure the node's `moduledef` should be retained:
                 should_keep_node &= self.should_retain_moduledef(node.hir);
 
-                // Make sure the root node doesn't get dropped:
-                should_keep_node |= *node_idx == root_idx;
+                // Make sure the root nodes don't get dropped:
+                should_keep_node |= root_idxs.contains(node_idx);
 
                 should_keep_node
             })
@@ -197,9 +223,19 @@ impl<'a> Filter<'a> {
             graph.remove_node(node_idx);
         }
 
-        // Drop any "uses" edges, if necessary:
+        // Drop any "uses" (and "re-exports") edges, if necessary:
         if self.options.selection.no_uses {
-            graph.retain_edges(|graph, edge_idx| graph[edge_idx] == Relationship::Owns);
+            graph.retain_edges(|graph, edge_idx| {
+                !matches!(
+                    graph[edge_idx],
+                    Relationship::Uses | Relationship::Reexports
+                )
+            });
+        }
+
+        // Drop any "implements" edges, if necessary:
+        if self.options.selection.no_impls {
+            graph.retain_edges(|graph, edge_idx| graph[edge_idx] != Relationship::Implements);
         }
 
         // The edge-reconciliation above may have resulted in redundant edges, so we need to remove those:
@@ -224,12 +260,17 @@ impl<'a> Filter<'a> {
         });
 
         // The above filters may have created disconnected sub-graphs.
-        // We're only interested in the sub-graph containing the `root_idx` though,
-        // so we query the graph for all node reachable from `root_node`:
-        let nodes_reachable_from_root = Self::nodes_reachable_from(&graph, root_idx);
+        // We're only interested in the sub-graphs containing the `root_idxs` though,
+        // so we query the graph for all node reachable from any root node:
+        let nodes_reachable_from_root: HashSet<NodeIndex> = root_idxs
+            .iter()
+            .flat_map(|root_idx| Self::nodes_reachable_from(&graph, *root_idx))
+            .collect();
 
         debug_assert!(
-            nodes_reachable_from_root.contains(&root_idx),
+            root_idxs
+                .iter()
+                .all(|root_idx| nodes_reachable_from_root.contains(root_idx)),
             "{}",
             ROOT_DROP_ERR_MSG
         );
@@ -237,7 +278,13 @@ impl<'a> Filter<'a> {
         // And drop any node that wasn't reachable from `root`:
         graph.retain_nodes(|_graph, node_idx| nodes_reachable_from_root.contains(&node_idx));
 
-        debug_assert!(graph.contains_node(root_idx), "{}", ROOT_DROP_ERR_MSG);
+        debug_assert!(
+            root_idxs
+                .iter()
+                .all(|root_idx| graph.contains_node(*root_idx)),
+            "{}",
+            ROOT_DROP_ERR_MSG
+        );
 
         if self.options.selection.no_owns {
             // drop all "owns" edges:
@@ -257,11 +304,17 @@ impl<'a> Filter<'a> {
                     .neighbors_directed(node_idx, Direction::Incoming)
                     .count();
 
-                node_idx == root_idx || (out_degree + in_degree) > 0
+                root_idxs.contains(&node_idx) || (out_degree + in_degree) > 0
             });
         }
 
-        debug_assert!(graph.contains_node(root_idx), "{}", ROOT_DROP_ERR_MSG);
+        debug_assert!(
+            root_idxs
+                .iter()
+                .all(|root_idx| graph.contains_node(*root_idx)),
+            "{}",
+            ROOT_DROP_ERR_MSG
+        );
 
         Ok(graph)
     }
@@ -326,11 +379,11 @@ impl<'a> Filter<'a> {
     }
 
     fn should_retain_const(&self, _const_hir: hir::Const) -> bool {
-        false
+        self.options.body_deps
     }
 
     fn should_retain_static(&self, _static_hir: hir::Static) -> bool {
-        false
+        self.options.body_deps
     }
 
     fn should_retain_trait(&self, _trait_hir: hir::Trait) -> bool {
@@ -372,7 +425,7 @@ impl<'a> Filter<'a> {
             return true;
         };
 
-        self.krate != import_krate
+        !self.krates.contains(&import_krate)
     }
 
     fn nodes_reachable_from(
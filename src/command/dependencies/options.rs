@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, path::PathBuf, str::FromStr};
 
 use clap::Parser;
 
@@ -83,6 +83,78 @@ impl Display for SplinesType {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    Dot,
+    Json,
+    Mermaid,
+    PlantUml,
+    GraphMl,
+    Gexf,
+}
+
+impl FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(Self::Dot),
+            "json" => Ok(Self::Json),
+            "mermaid" => Ok(Self::Mermaid),
+            "plantuml" => Ok(Self::PlantUml),
+            "graphml" => Ok(Self::GraphMl),
+            "gexf" => Ok(Self::Gexf),
+            _ => Err("Unrecognized output format"),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Dot => "dot",
+            Self::Json => "json",
+            Self::Mermaid => "mermaid",
+            Self::PlantUml => "plantuml",
+            Self::GraphMl => "graphml",
+            Self::Gexf => "gexf",
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Granularity {
+    Items,
+    Types,
+    Modules,
+    TopLevelModules,
+}
+
+impl FromStr for Granularity {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "items" => Ok(Self::Items),
+            "types" => Ok(Self::Types),
+            "modules" => Ok(Self::Modules),
+            "top-level-modules" => Ok(Self::TopLevelModules),
+            _ => Err("Unrecognized granularity"),
+        }
+    }
+}
+
+impl Display for Granularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Items => "items",
+            Self::Types => "types",
+            Self::Modules => "modules",
+            Self::TopLevelModules => "top-level-modules",
+        })
+    }
+}
+
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
 #[group(id = "GenerateSelectionOptions")]
 pub struct Options {
@@ -99,6 +171,12 @@ pub struct Options {
     #[arg(long = "acyclic", conflicts_with = "focus_on")]
     pub acyclic: bool,
 
+    /// The granularity at which to require the graph to be acyclic,
+    /// collapsing items into their owning types or modules
+    /// (e.g. items, types, modules, top-level-modules).
+    #[arg(long = "granularity", default_value = "items", requires = "acyclic")]
+    pub granularity: Granularity,
+
     /// The graph layout algorithm to use
     /// (e.g. none, dot, neato, twopi, circo, fdp, sfdp).
     #[arg(long = "layout", default_value = "neato")]
@@ -109,6 +187,17 @@ pub struct Options {
     #[arg(long = "splines", default_value = "line")]
     pub splines: SplinesType,
 
+    /// The output format to use
+    /// (e.g. dot, json, mermaid, plantuml, graphml, gexf).
+    #[arg(long = "format", default_value = "dot")]
+    pub format: OutputFormat,
+
+    /// Render the graph as an SVG image to the given file,
+    /// using the built-in renderer instead of requiring Graphviz
+    /// (which requires `--layout dot` and `--splines spline`).
+    #[arg(long = "output")]
+    pub output: Option<PathBuf>,
+
     /// Focus the graph on a particular path or use-tree's environment,
     /// e.g. "foo::bar::{self, baz, blee::*}".
     #[arg(long = "focus-on")]
@@ -122,6 +211,21 @@ pub struct Options {
     /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
     #[arg(long = "cfg-test")]
     pub cfg_test: bool,
+
+    /// Add "uses" edges for the items referenced within function bodies
+    /// (i.e. called functions, consts, statics and constructed types).
+    #[arg(long = "body-deps")]
+    pub body_deps: bool,
+
+    /// Analyze all of the workspace's member packages as a single combined graph
+    /// (i.e. the library target of each, or its binary targets if it has none).
+    #[arg(long = "workspace", conflicts_with_all = ["package", "bin", "acyclic"])]
+    pub workspace: bool,
+
+    /// Analyze all of the package's targets (i.e. its library, binaries,
+    /// examples, tests and benches) as a single combined graph.
+    #[arg(long = "all-targets", conflicts_with_all = ["lib", "bin", "workspace", "acyclic"])]
+    pub all_targets: bool,
 }
 
 // Important:
@@ -141,6 +245,10 @@ pub struct SelectionOptions {
     #[arg(long = "no-fns")]
     pub no_fns: bool,
 
+    /// Filter out "implements" edges (i.e. trait impls) from graph.
+    #[arg(long = "no-impls")]
+    pub no_impls: bool,
+
     /// Filter out modules (e.g. `mod foo`, `mod foo {}`) from graph.
     #[clap(long = "no-modules")]
     pub no_modules: bool,
@@ -161,7 +269,7 @@ pub struct SelectionOptions {
     #[arg(long = "no-types")]
     pub no_types: bool,
 
-    /// Filter out "use" edges from graph.
+    /// Filter out "use" (as well as "re-export") edges from graph.
     #[arg(long = "no-uses")]
     pub no_uses: bool,
 
@@ -0,0 +1,175 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying crate as a graph in GEXF format.
+//!
+//! See: https://gexf.net/
+
+use std::fmt;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    graph::{Edge, Graph, Node},
+    utils::xml_escape,
+};
+
+use super::{NODE_ATTRIBUTES, node_attribute_values};
+
+const INDENTATION: &str = "    ";
+
+pub struct GexfPrinter<'a> {
+    member_krates: &'a [hir::Crate],
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> GexfPrinter<'a> {
+    pub fn new(member_krates: &'a [hir::Crate], db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self {
+            member_krates,
+            db,
+            edition,
+        }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        start_node_idx: NodeIndex,
+    ) -> Result<(), anyhow::Error> {
+        let label = xml_escape(&graph[start_node_idx].display_path(self.db, self.edition));
+        let creator = env!("CARGO_PKG_NAME");
+        let i = INDENTATION;
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+
+        indoc::writedoc!(
+            f,
+            r#"
+            <gexf xmlns="http://gexf.net/1.3" version="1.3">
+            {i}<meta>
+            {i}{i}<creator>{creator}</creator>
+            {i}{i}<description>{label}</description>
+            {i}</meta>
+            {i}<graph mode="static" defaultedgetype="directed">
+            "#,
+        )?;
+
+        self.fmt_attributes(f)?;
+        self.fmt_nodes(f, graph)?;
+        self.fmt_edges(f, graph)?;
+
+        writeln!(f, "{i}</graph>")?;
+
+        writeln!(f, "</gexf>")?;
+
+        Ok(())
+    }
+
+    fn fmt_attributes(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let i = INDENTATION;
+
+        writeln!(f, r#"{i}{i}<attributes class="node">"#)?;
+        for (name, ty) in NODE_ATTRIBUTES {
+            writeln!(
+                f,
+                r#"{i}{i}{i}<attribute id="{name}" title="{name}" type="{ty}"/>"#
+            )?;
+        }
+        writeln!(f, "{i}{i}</attributes>")?;
+
+        writeln!(f, r#"{i}{i}<attributes class="edge">"#)?;
+        writeln!(
+            f,
+            r#"{i}{i}{i}<attribute id="relationship" title="relationship" type="string"/>"#
+        )?;
+        writeln!(f, "{i}{i}</attributes>")?;
+
+        Ok(())
+    }
+
+    fn fmt_nodes(&self, f: &mut dyn fmt::Write, graph: &Graph<Node, Edge>) -> fmt::Result {
+        let mut nodes: Vec<(String, &Node)> = graph
+            .node_weights()
+            .map(|node| (node.display_path(self.db, self.edition), node))
+            .collect();
+
+        nodes.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        let i = INDENTATION;
+
+        writeln!(f, "{i}{i}<nodes>")?;
+
+        for (path, node) in nodes {
+            let path = xml_escape(&path);
+            let values = node_attribute_values(node, self.member_krates, self.db, self.edition);
+
+            writeln!(f, r#"{i}{i}{i}<node id="{path}" label="{path}">"#)?;
+            writeln!(f, "{i}{i}{i}{i}<attvalues>")?;
+
+            for ((name, _), value) in NODE_ATTRIBUTES.iter().zip(values) {
+                let value = xml_escape(&value);
+                writeln!(
+                    f,
+                    r#"{i}{i}{i}{i}{i}<attvalue for="{name}" value="{value}"/>"#
+                )?;
+            }
+
+            writeln!(f, "{i}{i}{i}{i}</attvalues>")?;
+            writeln!(f, "{i}{i}{i}</node>")?;
+        }
+
+        writeln!(f, "{i}{i}</nodes>")?;
+
+        Ok(())
+    }
+
+    fn fmt_edges(&self, f: &mut dyn fmt::Write, graph: &Graph<Node, Edge>) -> fmt::Result {
+        let mut edges: Vec<(String, String, &'static str)> = graph
+            .edge_indices()
+            .map(|edge_idx| {
+                let edge = &graph[edge_idx];
+                let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+                let source = graph[source_idx].display_path(self.db, self.edition);
+                let target = graph[target_idx].display_path(self.db, self.edition);
+
+                (source, target, edge.display_name())
+            })
+            .collect();
+
+        edges.sort();
+
+        let i = INDENTATION;
+
+        writeln!(f, "{i}{i}<edges>")?;
+
+        for (index, (source, target, relationship)) in edges.into_iter().enumerate() {
+            let source = xml_escape(&source);
+            let target = xml_escape(&target);
+
+            writeln!(
+                f,
+                r#"{i}{i}{i}<edge id="{index}" source="{source}" target="{target}" label="{relationship}">"#
+            )?;
+            writeln!(f, "{i}{i}{i}{i}<attvalues>")?;
+            writeln!(
+                f,
+                r#"{i}{i}{i}{i}{i}<attvalue for="relationship" value="{relationship}"/>"#
+            )?;
+            writeln!(f, "{i}{i}{i}{i}</attvalues>")?;
+            writeln!(f, "{i}{i}{i}</edge>")?;
+        }
+
+        writeln!(f, "{i}{i}</edges>")?;
+
+        Ok(())
+    }
+}
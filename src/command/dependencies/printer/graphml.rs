@@ -0,0 +1,161 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying crate as a graph in GraphML format.
+//!
+//! See: http://graphml.graphdrawing.org/specification.html
+
+use std::fmt;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    graph::{Edge, Graph, Node},
+    utils::xml_escape,
+};
+
+use super::{NODE_ATTRIBUTES, node_attribute_values};
+
+const INDENTATION: &str = "    ";
+
+pub struct GraphMlPrinter<'a> {
+    member_krates: &'a [hir::Crate],
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> GraphMlPrinter<'a> {
+    pub fn new(member_krates: &'a [hir::Crate], db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self {
+            member_krates,
+            db,
+            edition,
+        }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        start_node_idx: NodeIndex,
+    ) -> Result<(), anyhow::Error> {
+        let label = xml_escape(&graph[start_node_idx].display_path(self.db, self.edition));
+        let i = INDENTATION;
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+
+        indoc::writedoc!(
+            f,
+            r#"
+            <graphml xmlns="http://graphml.graphdrawing.org/xmlns"
+            {i}xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+            {i}xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">
+            "#,
+        )?;
+
+        self.fmt_keys(f)?;
+
+        writeln!(f, r#"{i}<graph id="{label}" edgedefault="directed">"#)?;
+
+        self.fmt_nodes(f, graph)?;
+        self.fmt_edges(f, graph)?;
+
+        writeln!(f, "{i}</graph>")?;
+
+        writeln!(f, "</graphml>")?;
+
+        Ok(())
+    }
+
+    fn fmt_keys(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let i = INDENTATION;
+
+        writeln!(
+            f,
+            r#"{i}<key id="label" for="node" attr.name="label" attr.type="string"/>"#
+        )?;
+
+        for (name, ty) in NODE_ATTRIBUTES {
+            writeln!(
+                f,
+                r#"{i}<key id="{name}" for="node" attr.name="{name}" attr.type="{ty}"/>"#
+            )?;
+        }
+
+        writeln!(
+            f,
+            r#"{i}<key id="relationship" for="edge" attr.name="relationship" attr.type="string"/>"#
+        )?;
+
+        Ok(())
+    }
+
+    fn fmt_nodes(&self, f: &mut dyn fmt::Write, graph: &Graph<Node, Edge>) -> fmt::Result {
+        let mut nodes: Vec<(String, &Node)> = graph
+            .node_weights()
+            .map(|node| (node.display_path(self.db, self.edition), node))
+            .collect();
+
+        nodes.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        let i = INDENTATION;
+
+        for (path, node) in nodes {
+            let path = xml_escape(&path);
+            let values = node_attribute_values(node, self.member_krates, self.db, self.edition);
+
+            writeln!(f, r#"{i}{i}<node id="{path}">"#)?;
+            writeln!(f, r#"{i}{i}{i}<data key="label">{path}</data>"#)?;
+
+            for ((name, _), value) in NODE_ATTRIBUTES.iter().zip(values) {
+                let value = xml_escape(&value);
+                writeln!(f, r#"{i}{i}{i}<data key="{name}">{value}</data>"#)?;
+            }
+
+            writeln!(f, "{i}{i}</node>")?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_edges(&self, f: &mut dyn fmt::Write, graph: &Graph<Node, Edge>) -> fmt::Result {
+        let mut edges: Vec<(String, String, &'static str)> = graph
+            .edge_indices()
+            .map(|edge_idx| {
+                let edge = &graph[edge_idx];
+                let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+                let source = graph[source_idx].display_path(self.db, self.edition);
+                let target = graph[target_idx].display_path(self.db, self.edition);
+
+                (source, target, edge.display_name())
+            })
+            .collect();
+
+        edges.sort();
+
+        let i = INDENTATION;
+
+        for (index, (source, target, relationship)) in edges.into_iter().enumerate() {
+            let source = xml_escape(&source);
+            let target = xml_escape(&target);
+
+            writeln!(
+                f,
+                r#"{i}{i}<edge id="e{index}" source="{source}" target="{target}">"#
+            )?;
+            writeln!(
+                f,
+                r#"{i}{i}{i}<data key="relationship">{relationship}</data>"#
+            )?;
+            writeln!(f, "{i}{i}</edge>")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying crate as a graph in JSON format.
+
+use std::fmt;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use json::JsonValue;
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    analyzer,
+    graph::{Edge, Graph, Node},
+};
+
+use super::NodeAttributes;
+
+const INDENTATION: u16 = 4;
+
+pub struct JsonPrinter<'a> {
+    member_krates: &'a [hir::Crate],
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> JsonPrinter<'a> {
+    pub fn new(member_krates: &'a [hir::Crate], db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self {
+            member_krates,
+            db,
+            edition,
+        }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        start_node_idx: NodeIndex,
+    ) -> Result<(), anyhow::Error> {
+        let root = json::object! {
+            "crate": analyzer::crate_name(self.member_krates[0], self.db),
+            "root": graph[start_node_idx].display_path(self.db, self.edition),
+            "nodes": self.nodes(graph),
+            "edges": self.edges(graph),
+        };
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+
+    fn nodes(&self, graph: &Graph<Node, Edge>) -> JsonValue {
+        let mut nodes: Vec<(String, JsonValue)> = graph
+            .node_weights()
+            .map(|node| (node.display_path(self.db, self.edition), self.node(node)))
+            .collect();
+
+        nodes.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        JsonValue::Array(nodes.into_iter().map(|(_, node)| node).collect())
+    }
+
+    fn node(&self, node: &Node) -> JsonValue {
+        let attributes = NodeAttributes::new(node, self.member_krates, self.db, self.edition);
+
+        json::object! {
+            "path": node.display_path(self.db, self.edition),
+            "name": attributes.name,
+            "kind": attributes.kind,
+            "visibility": attributes.visibility,
+            "crate": attributes.krate,
+            "external": attributes.external,
+            "attrs": json::object! {
+                "cfgs": attributes.cfgs,
+                "test": attributes.test,
+            },
+        }
+    }
+
+    fn edges(&self, graph: &Graph<Node, Edge>) -> JsonValue {
+        let mut edges: Vec<(String, String, &'static str)> = graph
+            .edge_indices()
+            .map(|edge_idx| {
+                let edge = &graph[edge_idx];
+                let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+                let source = graph[source_idx].display_path(self.db, self.edition);
+                let target = graph[target_idx].display_path(self.db, self.edition);
+
+                (source, target, edge.display_name())
+            })
+            .collect();
+
+        edges.sort();
+
+        JsonValue::Array(
+            edges
+                .into_iter()
+                .map(|(source, target, relationship)| {
+                    json::object! {
+                        "source": source,
+                        "target": target,
+                        "relationship": relationship,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,223 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying crate as a graph in Mermaid's flowchart format.
+
+use std::{collections::HashMap, fmt};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    analyzer,
+    command::dependencies::{options::Options, theme::node_styles},
+    graph::{Edge, Graph, Node},
+    item::ItemVisibility,
+};
+
+use super::is_external;
+
+const INDENTATION: &str = "    ";
+
+pub struct MermaidPrinter<'a> {
+    options: &'a Options,
+    member_krates: &'a [hir::Crate],
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> MermaidPrinter<'a> {
+    pub fn new(
+        options: &'a Options,
+        member_krates: &'a [hir::Crate],
+        db: &'a dyn HirDatabase,
+        edition: Edition,
+    ) -> Self {
+        Self {
+            options,
+            member_krates,
+            db,
+            edition,
+        }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        start_node_idx: NodeIndex,
+    ) -> Result<(), anyhow::Error> {
+        let root_node = &graph[start_node_idx];
+        let label = root_node.display_path(self.db, self.edition);
+
+        writeln!(f, "---")?;
+        writeln!(f, "title: {label}")?;
+        writeln!(f, "---")?;
+
+        writeln!(f, "flowchart LR")?;
+
+        writeln!(f)?;
+
+        self.fmt_class_defs(f)?;
+
+        writeln!(f)?;
+
+        // Mermaid is rather picky about the characters it accepts in node ids,
+        // so we refer to nodes by their index in path-sorted order instead:
+        let mut nodes: Vec<(String, NodeIndex)> = graph
+            .node_indices()
+            .map(|node_idx| {
+                (
+                    graph[node_idx].display_path(self.db, self.edition),
+                    node_idx,
+                )
+            })
+            .collect();
+
+        nodes.sort();
+
+        let ids: HashMap<NodeIndex, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(id, (_, node_idx))| (*node_idx, id))
+            .collect();
+
+        self.fmt_nodes(f, graph, &nodes, &ids)?;
+
+        writeln!(f)?;
+
+        self.fmt_edges(f, graph, &ids)?;
+
+        Ok(())
+    }
+
+    fn fmt_class_defs(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let styles = node_styles();
+
+        let class_defs = [
+            ("krate", styles.krate),
+            ("pub_global", styles.visibility.pub_global),
+            ("pub_crate", styles.visibility.pub_crate),
+            ("pub_module", styles.visibility.pub_module),
+            ("pub_super", styles.visibility.pub_super),
+            ("pub_private", styles.visibility.pub_private),
+        ];
+
+        let i = INDENTATION;
+
+        for (class, style) in class_defs {
+            writeln!(f, "{i}classDef {class} fill:{}", style.fill_color)?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_nodes(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        nodes: &[(String, NodeIndex)],
+        ids: &HashMap<NodeIndex, usize>,
+    ) -> fmt::Result {
+        let i = INDENTATION;
+
+        for (path, node_idx) in nodes {
+            let node = &graph[*node_idx];
+
+            let id = ids[node_idx];
+            let header = self.node_header(node);
+            let body = self.node_body(path);
+            let class = self.node_class(node);
+
+            let label = escape(&format!("{header}<br/>{body}"));
+
+            writeln!(f, r#"{i}n{id}["{label}"]:::{class}"#)?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_edges(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        ids: &HashMap<NodeIndex, usize>,
+    ) -> fmt::Result {
+        let mut edges: Vec<_> = graph
+            .edge_indices()
+            .map(|edge_idx| {
+                let edge = &graph[edge_idx];
+                let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+                (ids[&source_idx], ids[&target_idx], edge)
+            })
+            .collect();
+
+        edges.sort_by_key(|(source, target, edge)| (*source, *target, edge.display_name()));
+
+        let i = INDENTATION;
+
+        for (source, target, edge) in edges {
+            let label = edge.display_name();
+            let arrow = match edge {
+                Edge::Owns => "-->",
+                Edge::Uses | Edge::Calls => "-.->",
+                Edge::Reexports => "==>",
+                Edge::Implements => "--o",
+            };
+
+            writeln!(f, "{i}n{source} {arrow}|{label}| n{target}")?;
+        }
+
+        Ok(())
+    }
+
+    fn node_header(&self, node: &Node) -> String {
+        let is_external = is_external(node, self.member_krates, self.db);
+        let is_crate = analyzer::moduledef_is_crate(node.hir, self.db);
+
+        let kind = node.kind_display_name(self.db, self.edition);
+
+        if is_external {
+            format!("external {kind}")
+        } else if is_crate {
+            kind.to_string()
+        } else {
+            format!("{} {kind}", node.visibility(self.db, self.edition))
+        }
+    }
+
+    fn node_body(&self, path: &str) -> String {
+        if self.options.selection.no_externs {
+            // Try to drop the crate-name from the path if externs are being filtered:
+            if let Some((_crate_name, relative_path)) = path.split_once("::") {
+                return relative_path.to_owned();
+            }
+        }
+
+        path.to_owned()
+    }
+
+    fn node_class(&self, node: &Node) -> &'static str {
+        if analyzer::moduledef_is_crate(node.hir, self.db) {
+            return "krate";
+        }
+
+        match &node.visibility(self.db, self.edition) {
+            ItemVisibility::Crate => "pub_crate",
+            ItemVisibility::Module(_) => "pub_module",
+            ItemVisibility::Private => "pub_private",
+            ItemVisibility::Public => "pub_global",
+            ItemVisibility::Super => "pub_super",
+        }
+    }
+}
+
+/// Escapes characters that would otherwise terminate a quoted Mermaid label.
+fn escape(label: &str) -> String {
+    label.replace('"', "#quot;")
+}
@@ -0,0 +1,368 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying crate as a PlantUML package/class diagram.
+
+use std::{collections::HashMap, fmt};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::graph::NodeIndex;
+
+use crate::{
+    analyzer,
+    command::dependencies::theme::node_styles,
+    graph::{Edge, Graph, Node},
+    item::ItemVisibility,
+};
+
+use super::is_external;
+
+const INDENTATION: &str = "  ";
+
+/// How a node gets rendered within the diagram.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Element {
+    /// Modules, rendered as (nestable) packages.
+    Package,
+    /// Structs, enums, unions and traits, rendered as classes.
+    Class,
+    /// Items owned by a class (e.g. methods), rendered as its members.
+    Member,
+    /// Any other items (e.g. free functions), rendered as stereotyped classes.
+    Other,
+}
+
+pub struct PlantUmlPrinter<'a> {
+    member_krates: &'a [hir::Crate],
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> PlantUmlPrinter<'a> {
+    pub fn new(member_krates: &'a [hir::Crate], db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self {
+            member_krates,
+            db,
+            edition,
+        }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        start_node_idx: NodeIndex,
+    ) -> Result<(), anyhow::Error> {
+        let label = graph[start_node_idx].display_path(self.db, self.edition);
+
+        // PlantUML treats dots in element names as namespace separators,
+        // so we refer to nodes by their index in path-sorted order instead:
+        let mut nodes: Vec<(String, NodeIndex)> = graph
+            .node_indices()
+            .map(|node_idx| {
+                (
+                    graph[node_idx].display_path(self.db, self.edition),
+                    node_idx,
+                )
+            })
+            .collect();
+
+        nodes.sort();
+
+        let layout = Layout::new(graph, nodes);
+
+        writeln!(f, "@startuml")?;
+
+        writeln!(f)?;
+
+        writeln!(f, "title {label}")?;
+        writeln!(f, "hide empty members")?;
+
+        writeln!(f)?;
+
+        for &id in &layout.roots {
+            self.fmt_element(f, graph, &layout, id, 0)?;
+        }
+
+        writeln!(f)?;
+
+        self.fmt_relationships(f, graph, &layout)?;
+
+        writeln!(f)?;
+
+        writeln!(f, "@enduml")?;
+
+        Ok(())
+    }
+
+    fn fmt_element(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        layout: &Layout,
+        id: usize,
+        depth: usize,
+    ) -> fmt::Result {
+        let (path, node_idx) = &layout.nodes[id];
+        let node = &graph[*node_idx];
+
+        // Nested elements are qualified by their enclosing packages already:
+        let label = if depth > 0 {
+            node.display_name(self.db, self.edition)
+        } else {
+            path.clone()
+        };
+
+        let kind = self.node_kind(node);
+        let color = self.node_color(node);
+
+        let indentation = INDENTATION.repeat(depth);
+        let i = indentation.as_str();
+        let children = layout.children(id);
+
+        match layout.elements[id] {
+            Element::Package => {
+                let stereotype = if analyzer::moduledef_is_crate(node.hir, self.db) {
+                    " <<crate>>"
+                } else {
+                    ""
+                };
+
+                write!(f, r#"{i}package "{label}" as n{id}{stereotype} {color}"#)?;
+
+                if children.is_empty() {
+                    writeln!(f, " {{}}")?;
+                } else {
+                    writeln!(f, " {{")?;
+                    for &child_id in children {
+                        self.fmt_element(f, graph, layout, child_id, depth + 1)?;
+                    }
+                    writeln!(f, "{i}}}")?;
+                }
+            }
+            Element::Class | Element::Other => {
+                let keyword = match node.hir {
+                    hir::ModuleDef::Adt(hir::Adt::Enum(_)) => "enum",
+                    hir::ModuleDef::Trait(_) => "interface",
+                    _ => "class",
+                };
+
+                write!(f, r#"{i}{keyword} "{label}" as n{id} <<{kind}>> {color}"#)?;
+
+                if children.is_empty() {
+                    writeln!(f)?;
+                } else {
+                    writeln!(f, " {{")?;
+                    for &child_id in children {
+                        let member = &graph[layout.nodes[child_id].1];
+                        let member = self.member(member);
+                        writeln!(f, "{i}{INDENTATION}{member}")?;
+                    }
+                    writeln!(f, "{i}}}")?;
+                }
+            }
+            Element::Member => unreachable!("members are printed by their class"),
+        }
+
+        Ok(())
+    }
+
+    fn fmt_relationships(
+        &self,
+        f: &mut dyn fmt::Write,
+        graph: &Graph<Node, Edge>,
+        layout: &Layout,
+    ) -> fmt::Result {
+        let mut relationships: Vec<(usize, usize, Edge)> = vec![];
+
+        for edge_idx in graph.edge_indices() {
+            let edge = graph[edge_idx];
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+            let source = layout.ids[&source_idx];
+            let target = layout.ids[&target_idx];
+
+            match edge {
+                Edge::Uses | Edge::Reexports | Edge::Implements | Edge::Calls => {
+                    // Members can't be connected directly, so we connect their classes instead:
+                    let source = layout.element(source);
+                    let target = layout.element(target);
+
+                    if source != target {
+                        relationships.push((source, target, edge));
+                    }
+                }
+                Edge::Owns => {
+                    // Containment is expressed by nesting, unless the owner isn't a package or class:
+                    if !layout.is_nested(target) {
+                        relationships.push((source, target, edge));
+                    }
+                }
+            }
+        }
+
+        relationships.sort_by_key(|(source, target, edge)| (*source, *target, edge.display_name()));
+        relationships.dedup();
+
+        for (source, target, edge) in relationships {
+            let arrow = match edge {
+                Edge::Uses | Edge::Calls => "..>",
+                Edge::Owns => "*--",
+                Edge::Reexports => "-->",
+                Edge::Implements => "..|>",
+            };
+            let label = edge.display_name();
+
+            writeln!(f, "n{source} {arrow} n{target} : {label}")?;
+        }
+
+        Ok(())
+    }
+
+    fn member(&self, node: &Node) -> String {
+        let marker = match node.visibility(self.db, self.edition) {
+            ItemVisibility::Public => "+",
+            ItemVisibility::Crate => "~",
+            ItemVisibility::Module(_) | ItemVisibility::Super => "#",
+            ItemVisibility::Private => "-",
+        };
+        let name = node.display_name(self.db, self.edition);
+
+        match node.hir {
+            hir::ModuleDef::Function(_) => format!("{marker}{name}()"),
+            _ => format!("{marker}{name}"),
+        }
+    }
+
+    fn node_kind(&self, node: &Node) -> String {
+        let kind = node.kind_display_name(self.db, self.edition);
+
+        if is_external(node, self.member_krates, self.db) {
+            format!("external {kind}")
+        } else {
+            kind.to_string()
+        }
+    }
+
+    fn node_color(&self, node: &Node) -> String {
+        let styles = node_styles();
+
+        let is_crate = analyzer::moduledef_is_crate(node.hir, self.db);
+
+        let style = if is_crate {
+            styles.krate
+        } else {
+            match &node.visibility(self.db, self.edition) {
+                ItemVisibility::Crate => styles.visibility.pub_crate,
+                ItemVisibility::Module(_) => styles.visibility.pub_module,
+                ItemVisibility::Private => styles.visibility.pub_private,
+                ItemVisibility::Public => styles.visibility.pub_global,
+                ItemVisibility::Super => styles.visibility.pub_super,
+            }
+        };
+
+        style.fill_color.to_string()
+    }
+}
+
+/// The nesting of nodes, as derived from the graph's "owns" edges.
+struct Layout {
+    nodes: Vec<(String, NodeIndex)>,
+    ids: HashMap<NodeIndex, usize>,
+    elements: Vec<Element>,
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    roots: Vec<usize>,
+}
+
+impl Layout {
+    fn new(graph: &Graph<Node, Edge>, nodes: Vec<(String, NodeIndex)>) -> Self {
+        let ids: HashMap<NodeIndex, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(id, (_, node_idx))| (*node_idx, id))
+            .collect();
+
+        let mut parents: Vec<Option<usize>> = vec![None; nodes.len()];
+
+        for edge_idx in graph.edge_indices() {
+            if graph[edge_idx] != Edge::Owns {
+                continue;
+            }
+
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+            parents[ids[&target_idx]].get_or_insert(ids[&source_idx]);
+        }
+
+        let kinds: Vec<Element> = nodes
+            .iter()
+            .map(|(_, node_idx)| match graph[*node_idx].hir {
+                hir::ModuleDef::Module(_) => Element::Package,
+                hir::ModuleDef::Adt(_) | hir::ModuleDef::Trait(_) => Element::Class,
+                _ => Element::Other,
+            })
+            .collect();
+
+        let elements: Vec<Element> = (0..nodes.len())
+            .map(
+                |id| match (kinds[id], parents[id].map(|parent| kinds[parent])) {
+                    (Element::Other, Some(Element::Class)) => Element::Member,
+                    (kind, _) => kind,
+                },
+            )
+            .collect();
+
+        // Only packages and classes can contain other elements:
+        let parents: Vec<Option<usize>> = parents
+            .into_iter()
+            .enumerate()
+            .map(|(id, parent)| {
+                parent.filter(|&parent| match elements[parent] {
+                    Element::Package => elements[id] != Element::Member,
+                    Element::Class => elements[id] == Element::Member,
+                    Element::Member | Element::Other => false,
+                })
+            })
+            .collect();
+
+        let mut children: Vec<Vec<usize>> = vec![vec![]; nodes.len()];
+        let mut roots: Vec<usize> = vec![];
+
+        // Since ids are assigned in path-sorted order, so are children and roots:
+        for (id, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(parent) => children[*parent].push(id),
+                None => roots.push(id),
+            }
+        }
+
+        Self {
+            nodes,
+            ids,
+            elements,
+            parents,
+            children,
+            roots,
+        }
+    }
+
+    fn children(&self, id: usize) -> &[usize] {
+        &self.children[id]
+    }
+
+    fn is_nested(&self, id: usize) -> bool {
+        self.parents[id].is_some()
+    }
+
+    /// Returns the id of the element that represents the node in the diagram.
+    fn element(&self, id: usize) -> usize {
+        match (self.elements[id], self.parents[id]) {
+            (Element::Member, Some(parent)) => parent,
+            _ => id,
+        }
+    }
+}
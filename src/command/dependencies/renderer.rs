@@ -0,0 +1,227 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Renderer for drawing a crate's graph as an SVG image, without requiring Graphviz.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::bail;
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use layout::{
+    backends::svg::SVGWriter,
+    core::{
+        base::Orientation,
+        color::Color,
+        style::{LineStyleKind, StyleAttr},
+    },
+    gv::record::record_builder,
+    std_shapes::{
+        render::get_shape_size,
+        shapes::{Arrow, Element, LineEndKind},
+    },
+    topo::layout::VisualGraph,
+};
+use petgraph::graph::NodeIndex;
+
+use crate::graph::{Edge, Graph, Node};
+
+use super::{
+    options::{LayoutAlgorithm, Options, OutputFormat, SplinesType},
+    printer::DotPrinter,
+    theme::{Stroke, edge_styles},
+};
+
+const FONT_SIZE: usize = 10;
+const LINE_WIDTH: usize = 1;
+
+pub(super) struct SvgRenderer<'a> {
+    printer: DotPrinter<'a>,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> SvgRenderer<'a> {
+    pub fn new(
+        options: &'a Options,
+        member_krates: &'a [hir::Crate],
+        db: &'a dyn HirDatabase,
+        edition: Edition,
+    ) -> Self {
+        // Nodes are labeled and colored the same way as in the DOT output:
+        let printer = DotPrinter::new(options, member_krates, db, edition);
+
+        Self {
+            printer,
+            db,
+            edition,
+        }
+    }
+
+    /// Checks whether the renderer is able to honor the options
+    /// for rendering to the file at `path`.
+    pub fn validate(options: &Options, path: &Path) -> anyhow::Result<()> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+
+        match extension.as_deref() {
+            Some("svg") => {}
+            Some(extension) => bail!(
+                "Unsupported output file extension `.{extension}`, \
+                the built-in renderer only supports `.svg` files"
+            ),
+            None => bail!(
+                "Missing output file extension, \
+                the built-in renderer only supports `.svg` files"
+            ),
+        }
+
+        if options.format != OutputFormat::Dot {
+            bail!(
+                "Unsupported output format `{}` for `--output`, \
+                the built-in renderer only supports `--format dot`",
+                options.format
+            );
+        }
+
+        // The built-in renderer only provides a layered layout (akin to Graphviz's `dot`):
+        if options.layout != LayoutAlgorithm::Dot {
+            bail!(
+                "Unsupported layout algorithm `{}` for `--output`, \
+                the built-in renderer only supports `--layout dot`",
+                options.layout
+            );
+        }
+
+        // The built-in renderer draws edges as curves, which can neither be
+        // straight lines, nor orthogonal, nor be omitted:
+        if options.splines != SplinesType::Spline {
+            bail!(
+                "Unsupported splines type `{}` for `--output`, \
+                the built-in renderer only supports `--splines spline`",
+                options.splines
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Lays out the graph and renders it as SVG.
+    pub fn render(&self, graph: &Graph<Node, Edge>) -> anyhow::Result<String> {
+        // Same as `rankdir=LR` in the DOT output:
+        let orientation = Orientation::LeftToRight;
+
+        let mut visual_graph = VisualGraph::new(orientation);
+
+        // Add nodes in path-sorted order, for deterministic output:
+        let mut nodes: Vec<(String, NodeIndex)> = graph
+            .node_indices()
+            .map(|node_idx| {
+                (
+                    graph[node_idx].display_path(self.db, self.edition),
+                    node_idx,
+                )
+            })
+            .collect();
+
+        nodes.sort();
+
+        let mut handles = HashMap::new();
+
+        for (_, node_idx) in nodes {
+            let element = self.node_element(&graph[node_idx], orientation)?;
+            handles.insert(node_idx, visual_graph.add_node(element));
+        }
+
+        let mut edges: Vec<_> = graph
+            .edge_indices()
+            .map(|edge_idx| {
+                let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+                (handles[&source_idx], handles[&target_idx], graph[edge_idx])
+            })
+            .collect();
+
+        edges.sort_by_key(|(source, target, edge)| {
+            (source.get_index(), target.get_index(), edge.display_name())
+        });
+
+        for (source, target, edge) in edges {
+            visual_graph.add_edge(self.edge_arrow(&edge), source, target);
+        }
+
+        let mut writer = SVGWriter::new();
+
+        let debug_mode = false;
+        let disable_optimizations = false;
+        let disable_layout = false;
+        visual_graph.do_it(
+            debug_mode,
+            disable_optimizations,
+            disable_layout,
+            &mut writer,
+        );
+
+        Ok(writer.finalize())
+    }
+
+    fn node_element(&self, node: &Node, orientation: Orientation) -> anyhow::Result<Element> {
+        let label = self.printer.node_label(node)?;
+        let fill_color = self.printer.node_fill_color(node);
+
+        // Records are laid out perpendicular to the graph's orientation:
+        let orientation = orientation.flip();
+
+        let shape = record_builder(&label);
+        let size = get_shape_size(orientation, &shape, FONT_SIZE, false);
+        let look = StyleAttr::new(
+            Color::fast("black"),
+            LINE_WIDTH,
+            Some(Color::fast(&fill_color.to_string())),
+            0,
+            FONT_SIZE,
+        );
+
+        Ok(Element::create(shape, look, orientation, size))
+    }
+
+    fn edge_arrow(&self, edge: &Edge) -> Arrow {
+        let styles = edge_styles();
+
+        let style = match edge {
+            Edge::Uses => styles.uses,
+            Edge::Owns => styles.owns,
+            Edge::Reexports => styles.reexports,
+            Edge::Implements => styles.implements,
+            Edge::Calls => styles.calls,
+        };
+
+        let line_style = match style.stroke {
+            Stroke::Solid => LineStyleKind::Normal,
+            Stroke::Dashed => LineStyleKind::Dashed,
+            Stroke::Dotted => LineStyleKind::Dotted,
+        };
+
+        let look = StyleAttr::new(
+            Color::fast(&style.color.to_string()),
+            LINE_WIDTH,
+            None,
+            0,
+            FONT_SIZE,
+        );
+
+        Arrow::new(
+            LineEndKind::None,
+            LineEndKind::Arrow,
+            line_style,
+            edge.display_name(),
+            &look,
+            &None,
+            &None,
+        )
+    }
+}
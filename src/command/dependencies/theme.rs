@@ -16,7 +16,6 @@ impl fmt::Display for Color {
 
 #[derive(Clone, Debug)]
 pub(crate) struct ColorPalette {
-    #[allow(dead_code)]
     pub purple: Color,
     pub red: Color,
     pub orange: Color,
@@ -68,7 +67,6 @@ impl NodeStyle {
 
 #[derive(Clone, Debug)]
 pub(crate) struct NodeStyles {
-    #[allow(dead_code)]
     pub krate: NodeStyle,
     pub visibility: ItemVisibilityStyles,
     #[allow(dead_code)]
@@ -94,6 +92,7 @@ pub(crate) fn node_styles() -> NodeStyles {
 pub(crate) enum Stroke {
     Solid,
     Dashed,
+    Dotted,
 }
 
 impl fmt::Display for Stroke {
@@ -101,6 +100,7 @@ impl fmt::Display for Stroke {
         let name = match self {
             Self::Solid => "solid",
             Self::Dashed => "dashed",
+            Self::Dotted => "dotted",
         };
         write!(f, "{name}")
     }
@@ -122,6 +122,9 @@ impl EdgeStyle {
 pub(crate) struct EdgeStyles {
     pub owns: EdgeStyle,
     pub uses: EdgeStyle,
+    pub reexports: EdgeStyle,
+    pub implements: EdgeStyle,
+    pub calls: EdgeStyle,
 }
 
 pub(crate) fn edge_styles() -> EdgeStyles {
@@ -129,5 +132,8 @@ pub(crate) fn edge_styles() -> EdgeStyles {
     EdgeStyles {
         owns: EdgeStyle::new(color_palette.black, Stroke::Solid),
         uses: EdgeStyle::new(color_palette.gray, Stroke::Dashed),
+        reexports: EdgeStyle::new(color_palette.purple, Stroke::Dotted),
+        implements: EdgeStyle::new(color_palette.cyan, Stroke::Dashed),
+        calls: EdgeStyle::new(color_palette.orange, Stroke::Dashed),
     }
 }
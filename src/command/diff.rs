@@ -0,0 +1,11 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub mod options;
+
+pub(super) mod command;
+pub(super) mod graph_diff;
+pub(super) mod printer;
+pub(super) mod snapshot;
+pub(super) mod theme;
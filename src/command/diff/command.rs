@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use clap::Parser;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use crate::{
+    analyzer::{self, LoadOptions, load_workspace},
+    graph::{BuildOptions, GraphBuilder},
+    options::ProjectOptions,
+};
+
+use super::{
+    graph_diff::GraphDiff,
+    options::{Options, OutputFormat},
+    printer::{DotPrinter, TextPrinter},
+    snapshot::{Snapshot, SnapshotBuilder},
+};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        tracing::trace!("Building graph ...");
+
+        let new_snapshot = Self::snapshot(krate, db, edition)?;
+
+        tracing::trace!("Loading crate to compare against ...");
+
+        let project_options = ProjectOptions {
+            manifest_path: self.options.against.clone(),
+            ..self.options.project.clone()
+        };
+
+        let (old_krate, old_host, _old_vfs, old_edition) = load_workspace(
+            &self.options.general,
+            &project_options,
+            &self.load_options(),
+        )?;
+        let old_db: &dyn HirDatabase = old_host.raw_database();
+
+        tracing::trace!("Building graph to compare against ...");
+
+        // The crates live in separate databases, hence the need for switching between them:
+        let old_snapshot =
+            hir::attach_db_allow_change(old_db, || Self::snapshot(old_krate, old_db, old_edition))?;
+
+        tracing::trace!("Comparing graphs ...");
+
+        let diff = GraphDiff::between(&old_snapshot, &new_snapshot);
+
+        tracing::trace!("Printing diff ...");
+
+        let mut string = String::new();
+
+        match self.options.format {
+            OutputFormat::Text => {
+                let printer = TextPrinter::new();
+                printer.fmt(&mut string, &diff)?;
+            }
+            OutputFormat::Dot => {
+                let crate_name = analyzer::crate_name(krate, db);
+                let printer = DotPrinter::new(&crate_name);
+                printer.fmt(&mut string, &diff)?;
+            }
+        }
+
+        print!("{string}");
+
+        Ok(())
+    }
+
+    fn snapshot(
+        krate: hir::Crate,
+        db: &dyn HirDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<Snapshot> {
+        let builder = GraphBuilder::new(db, edition, krate, BuildOptions::default());
+        let (graph, _crate_node_idx) = builder.build()?;
+
+        let builder = SnapshotBuilder::new(krate, db, edition);
+        Ok(builder.build(&graph))
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Differences between the graph snapshots of two checkouts of a crate.
+
+use crate::graph::Edge;
+
+use super::snapshot::Snapshot;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Change {
+    Added,
+    Removed,
+    Unchanged,
+}
+
+impl Change {
+    fn between(in_old: bool, in_new: bool) -> Self {
+        match (in_old, in_new) {
+            (false, _) => Self::Added,
+            (true, false) => Self::Removed,
+            (true, true) => Self::Unchanged,
+        }
+    }
+
+    pub fn marker(&self) -> char {
+        match self {
+            Self::Added => '+',
+            Self::Removed => '-',
+            Self::Unchanged => ' ',
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct NodeDiff {
+    pub path: String,
+    pub kind: String,
+    pub change: Change,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct EdgeDiff {
+    pub source: String,
+    pub target: String,
+    pub edge: Edge,
+    pub change: Change,
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub(crate) struct ChangeCounts {
+    pub added: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+}
+
+impl ChangeCounts {
+    fn new(changes: impl Iterator<Item = Change>) -> Self {
+        let mut counts = Self::default();
+
+        for change in changes {
+            match change {
+                Change::Added => counts.added += 1,
+                Change::Removed => counts.removed += 1,
+                Change::Unchanged => counts.unchanged += 1,
+            }
+        }
+
+        counts
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct GraphDiff {
+    /// The nodes of both snapshots, sorted by path.
+    pub nodes: Vec<NodeDiff>,
+    /// The edges of both snapshots, sorted by source, target and relationship.
+    pub edges: Vec<EdgeDiff>,
+}
+
+impl GraphDiff {
+    /// Compares the `old` snapshot against the `new` one, matching nodes by their paths.
+    pub fn between(old: &Snapshot, new: &Snapshot) -> Self {
+        // Items present in both snapshots take their kind from the new one:
+        let mut nodes: Vec<NodeDiff> = new
+            .nodes
+            .iter()
+            .chain(old.nodes.iter())
+            .map(|(path, kind)| NodeDiff {
+                path: path.clone(),
+                kind: kind.clone(),
+                change: Change::between(old.nodes.contains_key(path), new.nodes.contains_key(path)),
+            })
+            .collect();
+
+        nodes.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path));
+        nodes.dedup_by(|lhs, rhs| lhs.path == rhs.path);
+
+        let edges: Vec<EdgeDiff> = old
+            .edges
+            .union(&new.edges)
+            .map(|triple| {
+                let (source, target, edge) = triple.clone();
+
+                EdgeDiff {
+                    source,
+                    target,
+                    edge,
+                    change: Change::between(old.edges.contains(triple), new.edges.contains(triple)),
+                }
+            })
+            .collect();
+
+        Self { nodes, edges }
+    }
+
+    pub fn node_counts(&self) -> ChangeCounts {
+        ChangeCounts::new(self.nodes.iter().map(|node| node.change))
+    }
+
+    pub fn edge_counts(&self) -> ChangeCounts {
+        ChangeCounts::new(self.edges.iter().map(|edge| edge.change))
+    }
+}
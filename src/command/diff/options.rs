@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{fmt::Display, path::PathBuf, str::FromStr};
+
+use clap::Parser;
+
+use crate::options::{GeneralOptions, ProjectOptions};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    Text,
+    Dot,
+}
+
+impl FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "dot" => Ok(Self::Dot),
+            _ => Err("Unrecognized output format"),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Dot => "dot",
+        })
+    }
+}
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[group(id = "DiffOptions")]
+pub struct Options {
+    #[command(flatten)]
+    pub general: GeneralOptions,
+
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// Path to the Cargo.toml of the checkout to compare against
+    /// (e.g. a `git worktree` of the base branch).
+    #[arg(long = "against", conflicts_with = "project_json")]
+    pub against: PathBuf,
+
+    /// The output format to use (e.g. text, dot).
+    #[arg(long = "format", default_value = "text")]
+    pub format: OutputFormat,
+
+    /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
+    #[arg(long = "cfg-test")]
+    pub cfg_test: bool,
+}
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying the differences between two graphs as a Graphviz DOT overlay.
+
+use std::fmt;
+
+use crate::{
+    command::{
+        dependencies::theme::{Color, color_palette, edge_styles},
+        diff::graph_diff::{Change, GraphDiff},
+    },
+    graph::Edge,
+};
+
+const INDENTATION: &str = "    ";
+
+pub struct DotPrinter<'a> {
+    label: &'a str,
+}
+
+impl<'a> DotPrinter<'a> {
+    pub fn new(label: &'a str) -> Self {
+        Self { label }
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, diff: &GraphDiff) -> Result<(), anyhow::Error> {
+        let label = self.label;
+        let i = INDENTATION;
+
+        writeln!(f, "digraph {{")?;
+
+        writeln!(f)?;
+
+        indoc::writedoc!(
+            f,
+            r#"
+            {i}graph [
+            {i}    label={label:?},
+            {i}    labelloc=t,
+
+            {i}    pad=0.4,
+
+            {i}    layout=dot,
+            {i}    overlap=false,
+            {i}    splines="line",
+            {i}    rankdir=LR,
+
+            {i}    fontname="Helvetica",
+            {i}    fontsize="36",
+            {i}];
+            "#,
+        )?;
+
+        writeln!(f)?;
+
+        indoc::writedoc!(
+            f,
+            r#"
+            {i}node [
+            {i}    fontname="monospace",
+            {i}    fontsize="10",
+            {i}    shape="record",
+            {i}    style="filled",
+            {i}];
+            "#,
+        )?;
+
+        writeln!(f)?;
+
+        indoc::writedoc!(
+            f,
+            r#"
+            {i}edge [
+            {i}    fontname="monospace",
+            {i}    fontsize="10",
+            {i}];
+            "#,
+        )?;
+
+        writeln!(f)?;
+
+        for node in &diff.nodes {
+            let id = &node.path;
+            let label = format!("{}|{}", node.kind, node.path);
+            let fill_color = self.node_fill_color(node.change);
+            let change = Self::change_name(node.change);
+
+            writeln!(
+                f,
+                r#"{i}{id:?} [label={label:?}, fillcolor="{fill_color}"]; // {change:?} node"#
+            )?;
+        }
+
+        writeln!(f)?;
+
+        for edge in &diff.edges {
+            let source = &edge.source;
+            let target = &edge.target;
+            let label = edge.edge.display_name();
+            let change = Self::change_name(edge.change);
+
+            let style = match edge.edge {
+                Edge::Uses => edge_styles().uses,
+                Edge::Owns => edge_styles().owns,
+                Edge::Reexports => edge_styles().reexports,
+                Edge::Implements => edge_styles().implements,
+                Edge::Calls => edge_styles().calls,
+            };
+
+            let color = self.edge_color(edge.change).unwrap_or(style.color);
+
+            let constraint = match edge.edge {
+                Edge::Uses | Edge::Reexports | Edge::Implements | Edge::Calls => {
+                    "[constraint=false]"
+                }
+                Edge::Owns => "[constraint=true]",
+            };
+
+            writeln!(
+                f,
+                r#"{i}{source:?} -> {target:?} [label={label:?}, color="{color}", style="{stroke}"] {constraint}; // {change:?} edge"#,
+                stroke = style.stroke,
+            )?;
+        }
+
+        writeln!(f)?;
+
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+
+    fn node_fill_color(&self, change: Change) -> Color {
+        let color_palette = color_palette();
+
+        match change {
+            Change::Added => color_palette.green,
+            Change::Removed => color_palette.red,
+            Change::Unchanged => color_palette.white,
+        }
+    }
+
+    /// Returns the color for highlighting added or removed edges,
+    /// leaving unchanged edges to be styled as usual.
+    fn edge_color(&self, change: Change) -> Option<Color> {
+        let color_palette = color_palette();
+
+        match change {
+            Change::Added => Some(color_palette.green),
+            Change::Removed => Some(color_palette.red),
+            Change::Unchanged => None,
+        }
+    }
+
+    fn change_name(change: Change) -> &'static str {
+        match change {
+            Change::Added => "added",
+            Change::Removed => "removed",
+            Change::Unchanged => "unchanged",
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying the differences between two graphs as colored text.
+
+use std::fmt;
+
+use yansi::{Paint as _, Style};
+
+use crate::command::diff::{
+    graph_diff::{Change, ChangeCounts, GraphDiff},
+    theme::{Styles, styles},
+};
+
+pub struct TextPrinter;
+
+impl TextPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, diff: &GraphDiff) -> Result<(), anyhow::Error> {
+        let styles = styles();
+
+        writeln!(f)?;
+        Self::fmt_heading(f, "Nodes", diff.node_counts(), &styles)?;
+        writeln!(f)?;
+
+        for node in &diff.nodes {
+            let line = format!("{} {} {}", node.change.marker(), node.kind, node.path);
+            writeln!(f, "{}", line.paint(Self::style(node.change, &styles)))?;
+        }
+
+        writeln!(f)?;
+        Self::fmt_heading(f, "Edges", diff.edge_counts(), &styles)?;
+        writeln!(f)?;
+
+        for edge in &diff.edges {
+            let line = format!(
+                "{} {} -> {} ({})",
+                edge.change.marker(),
+                edge.source,
+                edge.target,
+                edge.edge.display_name()
+            );
+            writeln!(f, "{}", line.paint(Self::style(edge.change, &styles)))?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_heading(
+        f: &mut dyn fmt::Write,
+        title: &str,
+        counts: ChangeCounts,
+        styles: &Styles,
+    ) -> fmt::Result {
+        writeln!(
+            f,
+            "{title} ({added} added, {removed} removed, {unchanged} unchanged):",
+            title = title.paint(styles.chrome),
+            added = counts.added,
+            removed = counts.removed,
+            unchanged = counts.unchanged,
+        )
+    }
+
+    fn style(change: Change, styles: &Styles) -> Style {
+        match change {
+            Change::Added => styles.added,
+            Change::Removed => styles.removed,
+            Change::Unchanged => styles.unchanged,
+        }
+    }
+}
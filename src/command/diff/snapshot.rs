@@ -0,0 +1,79 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Snapshot of a crate's graph, keyed by display paths.
+//!
+//! Graphs loaded from different checkouts live in different databases,
+//! so their nodes can only be matched up by their paths.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use crate::{
+    analyzer,
+    graph::{Edge, Graph, Node},
+};
+
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub(crate) struct Snapshot {
+    /// The kinds of the crate's items, keyed by their paths.
+    pub nodes: BTreeMap<String, String>,
+    /// The (source, target, relationship) triples between the crate's items.
+    pub edges: BTreeSet<(String, String, Edge)>,
+}
+
+pub(crate) struct SnapshotBuilder<'a> {
+    krate: hir::Crate,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> SnapshotBuilder<'a> {
+    pub fn new(krate: hir::Crate, db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self { krate, db, edition }
+    }
+
+    /// Takes a snapshot of the graph's nodes and edges that are internal to the crate.
+    pub fn build(&self, graph: &Graph<Node, Edge>) -> Snapshot {
+        let mut snapshot = Snapshot::default();
+
+        for node_idx in graph.node_indices() {
+            let node = &graph[node_idx];
+
+            if !self.is_crate_node(node) {
+                continue;
+            }
+
+            let path = node.display_path(self.db, self.edition);
+            let kind = node.kind_display_name(self.db, self.edition).to_string();
+
+            snapshot.nodes.insert(path, kind);
+        }
+
+        for edge_idx in graph.edge_indices() {
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+            let (source, target) = (&graph[source_idx], &graph[target_idx]);
+
+            if !self.is_crate_node(source) || !self.is_crate_node(target) {
+                continue;
+            }
+
+            snapshot.edges.insert((
+                source.display_path(self.db, self.edition),
+                target.display_path(self.db, self.edition),
+                graph[edge_idx],
+            ));
+        }
+
+        snapshot
+    }
+
+    fn is_crate_node(&self, node: &Node) -> bool {
+        analyzer::krate(node.hir, self.db) == Some(self.krate)
+    }
+}
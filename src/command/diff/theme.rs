@@ -0,0 +1,25 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use yansi::Style;
+
+use crate::colors::cli::color_palette;
+
+#[derive(Clone, Debug)]
+pub(crate) struct Styles {
+    pub chrome: Style,
+    pub added: Style,
+    pub removed: Style,
+    pub unchanged: Style,
+}
+
+pub(crate) fn styles() -> Styles {
+    let color_palette = color_palette();
+    Styles {
+        chrome: Style::default().fg(color_palette.blue).bold(),
+        added: Style::default().fg(color_palette.green),
+        removed: Style::default().fg(color_palette.red),
+        unchanged: Style::default(),
+    }
+}
@@ -0,0 +1,170 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use anyhow::Context as _;
+use clap::Parser;
+
+use crate::{
+    analyzer::{self, LoadOptions},
+    command::{
+        dependencies::{
+            filter::Filter as GraphFilter,
+            options::{
+                Granularity, LayoutAlgorithm, Options as DependenciesOptions,
+                OutputFormat as GraphFormat, SplinesType,
+            },
+            printer::JsonPrinter as GraphJsonPrinter,
+        },
+        structure::{
+            filter::Filter as TreeFilter,
+            options::{
+                Options as StructureOptions, OutputFormat as TreeFormat,
+                SelectionOptions as TreeSelectionOptions, SortBy,
+            },
+            printer::JsonPrinter as TreeJsonPrinter,
+        },
+    },
+    graph::{BuildOptions, GraphBuilder},
+    tree::TreeBuilder,
+};
+
+use super::{options::Options, printer::HtmlPrinter};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        tracing::trace!("Building graph ...");
+
+        let dependencies_options = self.dependencies_options();
+
+        let builder = GraphBuilder::new(db, edition, krate, BuildOptions::default());
+        let (graph, crate_node_idx) = builder.build()?;
+
+        tracing::trace!("Filtering graph ...");
+
+        let filter = GraphFilter::new(
+            &dependencies_options,
+            db,
+            edition,
+            std::slice::from_ref(&krate),
+        );
+        let graph = filter.filter(&graph, crate_node_idx)?;
+
+        let mut graph_json = String::new();
+
+        let printer = GraphJsonPrinter::new(std::slice::from_ref(&krate), db, edition);
+        printer.fmt(&mut graph_json, &graph, crate_node_idx)?;
+
+        tracing::trace!("Building tree ...");
+
+        let structure_options = self.structure_options();
+
+        let builder = TreeBuilder::new(db, krate);
+        let tree = builder.build()?;
+
+        tracing::trace!("Filtering tree ...");
+
+        let filter = TreeFilter::new(&structure_options, krate, db, edition);
+        let tree = filter.filter(&tree)?;
+
+        let mut tree_json = String::new();
+
+        let printer = TreeJsonPrinter::new(&structure_options, db, edition);
+        printer.fmt(&mut tree_json, &tree)?;
+
+        tracing::trace!("Printing page ...");
+
+        let crate_name = analyzer::crate_name(krate, db);
+
+        let mut output = String::new();
+
+        let printer = HtmlPrinter::new();
+        printer.fmt(&mut output, &crate_name, &graph_json, &tree_json)?;
+
+        if let Some(path) = &self.options.output {
+            std::fs::write(path, output)
+                .with_context(|| format!("Failed to write page to {}", path.display()))?;
+        } else {
+            print!("{output}");
+        }
+
+        Ok(())
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: !(self.options.selection.no_uses
+                || self.options.selection.no_externs
+                || self.options.selection.no_sysroot),
+            workspace: false,
+            all_targets: false,
+        }
+    }
+
+    /// Returns the options for filtering and printing the embedded dependency graph.
+    fn dependencies_options(&self) -> DependenciesOptions {
+        DependenciesOptions {
+            general: self.options.general.clone(),
+            project: self.options.project.clone(),
+            selection: self.options.selection.clone(),
+            acyclic: false,
+            granularity: Granularity::Items,
+            layout: LayoutAlgorithm::Dot,
+            splines: SplinesType::Line,
+            format: GraphFormat::Json,
+            output: None,
+            focus_on: None,
+            max_depth: None,
+            cfg_test: self.options.cfg_test,
+            body_deps: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+
+    /// Returns the options for filtering and printing the embedded structure tree.
+    fn structure_options(&self) -> StructureOptions {
+        StructureOptions {
+            general: self.options.general.clone(),
+            project: self.options.project.clone(),
+            selection: TreeSelectionOptions {
+                no_fns: self.options.selection.no_fns,
+                no_traits: self.options.selection.no_traits,
+                no_types: self.options.selection.no_types,
+            },
+            sort_by: SortBy::Name,
+            sort_reversed: false,
+            format: TreeFormat::Json,
+            stats: false,
+            diff: None,
+            focus_on: None,
+            max_depth: None,
+            cfg_test: self.options.cfg_test,
+        }
+    }
+}
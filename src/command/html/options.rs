@@ -0,0 +1,33 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::{
+    command::dependencies::options::SelectionOptions,
+    options::{GeneralOptions, ProjectOptions},
+};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[group(id = "HtmlOptions")]
+pub struct Options {
+    #[command(flatten)]
+    pub general: GeneralOptions,
+
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    #[command(flatten)]
+    pub selection: SelectionOptions,
+
+    /// Write the page to the given file, instead of printing it.
+    #[arg(long = "output")]
+    pub output: Option<PathBuf>,
+
+    /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
+    #[arg(long = "cfg-test")]
+    pub cfg_test: bool,
+}
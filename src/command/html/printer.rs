@@ -0,0 +1,77 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying crate as a self-contained, interactive HTML page.
+
+use std::fmt;
+
+use crate::{
+    command::dependencies::theme::{color_palette, node_styles},
+    utils::xml_escape,
+};
+
+const TEMPLATE: &str = include_str!("template.html");
+
+const INDENTATION: &str = "    ";
+
+pub struct HtmlPrinter;
+
+impl HtmlPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Writes the page, with the crate's dependency graph and structure tree
+    /// (as printed by the `dependencies` and `structure` commands' JSON printers) embedded as data.
+    pub fn fmt(
+        &self,
+        f: &mut dyn fmt::Write,
+        crate_name: &str,
+        graph_json: &str,
+        tree_json: &str,
+    ) -> Result<(), anyhow::Error> {
+        let generator = format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+
+        let page = TEMPLATE
+            .replace("{{generator}}", &generator)
+            .replace("{{title}}", &xml_escape(crate_name))
+            .replace("{{styles}}", &self.styles())
+            .replace("{{graph}}", &script_escape(graph_json.trim_end()))
+            .replace("{{tree}}", &script_escape(tree_json.trim_end()));
+
+        f.write_str(&page)?;
+
+        Ok(())
+    }
+
+    fn styles(&self) -> String {
+        let node_styles = node_styles();
+        let color_palette = color_palette();
+
+        let variables = [
+            ("krate", node_styles.krate.fill_color),
+            ("pub-global", node_styles.visibility.pub_global.fill_color),
+            ("pub-crate", node_styles.visibility.pub_crate.fill_color),
+            ("pub-module", node_styles.visibility.pub_module.fill_color),
+            ("pub-super", node_styles.visibility.pub_super.fill_color),
+            ("pub-private", node_styles.visibility.pub_private.fill_color),
+            ("outgoing", color_palette.cyan),
+            ("incoming", color_palette.purple),
+        ];
+
+        let i = INDENTATION;
+
+        variables
+            .iter()
+            .map(|(name, color)| format!("{i}--{name}: {color};"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Escapes JSON for embedding within a `<script>` element,
+/// which would otherwise be terminated prematurely by any contained `</script>`.
+fn script_escape(json: &str) -> String {
+    json.replace("</", r"<\/")
+}
@@ -0,0 +1,123 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Calculator for a crate's per-module coupling metrics.
+
+use std::collections::{BTreeSet, HashMap};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use petgraph::{Direction, graph::NodeIndex, visit::EdgeRef as _};
+
+use crate::{
+    analyzer,
+    command::dependencies::{collapser::Collapser, options::Granularity},
+    graph::{Edge, Graph, Node},
+};
+
+use super::module_metrics::ModuleMetrics;
+
+pub(crate) struct Calculator<'a> {
+    krate: hir::Crate,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> Calculator<'a> {
+    pub fn new(krate: hir::Crate, db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self { krate, db, edition }
+    }
+
+    /// Calculates the metrics of each of the crate's modules, sorted by path.
+    pub fn calculate(&self, graph: &Graph<Node, Edge>, root_idx: NodeIndex) -> Vec<ModuleMetrics> {
+        let type_counts = self.type_counts(graph);
+
+        let collapser = Collapser::new(Granularity::Modules, self.db);
+        let (graph, _root_idx) = collapser.collapse(graph, root_idx);
+
+        let mut metrics: Vec<ModuleMetrics> = graph
+            .node_indices()
+            .filter(|node_idx| self.is_crate_module(&graph[*node_idx]))
+            .map(|node_idx| {
+                let path = graph[node_idx].display_path(self.db, self.edition);
+
+                let afferent = self.coupled_modules(&graph, node_idx, Direction::Incoming);
+                let efferent = self.coupled_modules(&graph, node_idx, Direction::Outgoing);
+
+                let (traits, adts) = type_counts.get(&path).copied().unwrap_or_default();
+
+                ModuleMetrics {
+                    path,
+                    afferent,
+                    efferent,
+                    traits,
+                    adts,
+                }
+            })
+            .collect();
+
+        metrics.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path));
+
+        metrics
+    }
+
+    /// Returns the number of other modules connected to the module via dependency edges
+    /// (i.e. "uses" or "re-exports") in the given direction.
+    fn coupled_modules(
+        &self,
+        graph: &Graph<Node, Edge>,
+        node_idx: NodeIndex,
+        direction: Direction,
+    ) -> usize {
+        let modules: BTreeSet<NodeIndex> = graph
+            .edges_directed(node_idx, direction)
+            .filter(|edge_ref| edge_ref.weight().is_dependency())
+            .map(|edge_ref| match direction {
+                Direction::Incoming => edge_ref.source(),
+                Direction::Outgoing => edge_ref.target(),
+            })
+            .filter(|other_idx| *other_idx != node_idx)
+            .filter(|other_idx| self.is_crate_module(&graph[*other_idx]))
+            .collect();
+
+        modules.len()
+    }
+
+    /// Returns the number of traits and ADTs owned by each module, keyed by the module's path.
+    fn type_counts(&self, graph: &Graph<Node, Edge>) -> HashMap<String, (usize, usize)> {
+        let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+
+        for edge_idx in graph.edge_indices() {
+            if graph[edge_idx] != Edge::Owns {
+                continue;
+            }
+
+            let (source_idx, target_idx) = graph.edge_endpoints(edge_idx).unwrap();
+
+            if !self.is_crate_module(&graph[source_idx]) {
+                continue;
+            }
+
+            let (traits, adts) = match graph[target_idx].hir {
+                hir::ModuleDef::Trait(_) => (1, 0),
+                hir::ModuleDef::Adt(_) => (0, 1),
+                _ => continue,
+            };
+
+            let path = graph[source_idx].display_path(self.db, self.edition);
+            let count = counts.entry(path).or_default();
+            count.0 += traits;
+            count.1 += adts;
+        }
+
+        counts
+    }
+
+    fn is_crate_module(&self, node: &Node) -> bool {
+        matches!(node.hir, hir::ModuleDef::Module(_))
+            && analyzer::krate(node.hir, self.db) == Some(self.krate)
+    }
+}
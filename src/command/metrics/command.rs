@@ -0,0 +1,113 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::cmp::Ordering;
+
+use clap::Parser;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::{self as ide};
+
+use crate::{
+    analyzer::{self, LoadOptions},
+    graph::{BuildOptions, GraphBuilder},
+};
+
+use super::{
+    calculator::Calculator,
+    module_metrics::ModuleMetrics,
+    options::{Options, OutputFormat, SortBy},
+    printer::{JsonPrinter, TextPrinter},
+};
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+pub struct Command {
+    #[command(flatten)]
+    pub options: Options,
+}
+
+impl Command {
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn sanitize(&mut self) {}
+
+    #[doc(hidden)]
+    pub fn run(
+        self,
+        krate: hir::Crate,
+        db: &ide::RootDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<()> {
+        let db: &dyn HirDatabase = db;
+
+        tracing::trace!("Building graph ...");
+
+        let builder = GraphBuilder::new(db, edition, krate, BuildOptions::default());
+        let (graph, crate_node_idx) = builder.build()?;
+
+        tracing::trace!("Calculating metrics ...");
+
+        let calculator = Calculator::new(krate, db, edition);
+        let mut metrics = calculator.calculate(&graph, crate_node_idx);
+
+        self.sort(&mut metrics);
+
+        let mut stdout = std::io::stdout();
+
+        match self.options.format {
+            OutputFormat::Text => {
+                let printer = TextPrinter::new();
+                printer.fmt(&mut stdout, &metrics[..])?;
+            }
+            OutputFormat::Json => {
+                let crate_name = analyzer::crate_name(krate, db);
+                let printer = JsonPrinter::new(&crate_name);
+                printer.fmt(&mut stdout, &metrics[..])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            cfg_test: self.options.cfg_test,
+            sysroot: false,
+            workspace: false,
+            all_targets: false,
+        }
+    }
+
+    fn sort(&self, metrics: &mut [ModuleMetrics]) {
+        fn compare(lhs: Option<f64>, rhs: Option<f64>) -> Ordering {
+            lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal)
+        }
+
+        let sort_by = self.options.sort_by;
+        let sort_reversed = self.options.sort_reversed;
+
+        metrics.sort_by(|lhs, rhs| {
+            let ordering = match sort_by {
+                SortBy::Name => lhs.path.cmp(&rhs.path),
+                SortBy::Afferent => lhs.afferent.cmp(&rhs.afferent),
+                SortBy::Efferent => lhs.efferent.cmp(&rhs.efferent),
+                SortBy::Instability => compare(lhs.instability(), rhs.instability()),
+                SortBy::Abstractness => compare(lhs.abstractness(), rhs.abstractness()),
+                SortBy::Distance => compare(lhs.distance(), rhs.distance()),
+            };
+
+            let ordering = if sort_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+
+            // Modules with equal metrics remain sorted by path, even if reversed:
+            ordering.then_with(|| lhs.path.cmp(&rhs.path))
+        });
+    }
+}
@@ -0,0 +1,49 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Robert C. Martin's package metrics, applied to a crate's modules.
+//!
+//! See: https://en.wikipedia.org/wiki/Software_package_metrics
+
+/// The coupling metrics of a single module.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct ModuleMetrics {
+    /// The module's path.
+    pub path: String,
+    /// The number of other modules that use the module ("Ca").
+    pub afferent: usize,
+    /// The number of other modules that the module uses ("Ce").
+    pub efferent: usize,
+    /// The number of traits owned by the module.
+    pub traits: usize,
+    /// The number of structs, enums and unions owned by the module.
+    pub adts: usize,
+}
+
+impl ModuleMetrics {
+    /// Returns the module's instability ("I"), i.e. `Ce / (Ca + Ce)`,
+    /// or `None` if the module is neither used by, nor using other modules.
+    pub fn instability(&self) -> Option<f64> {
+        let total = self.afferent + self.efferent;
+
+        (total > 0).then(|| self.efferent as f64 / total as f64)
+    }
+
+    /// Returns the module's abstractness ("A"), i.e. the ratio of traits to types,
+    /// or `None` if the module owns no types.
+    pub fn abstractness(&self) -> Option<f64> {
+        let total = self.traits + self.adts;
+
+        (total > 0).then(|| self.traits as f64 / total as f64)
+    }
+
+    /// Returns the module's normalized distance from the main sequence ("D"), i.e. `|A + I - 1|`,
+    /// or `None` if either of its instability or abstractness is undefined.
+    pub fn distance(&self) -> Option<f64> {
+        let instability = self.instability()?;
+        let abstractness = self.abstractness()?;
+
+        Some((abstractness + instability - 1.0).abs())
+    }
+}
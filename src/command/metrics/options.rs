@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{fmt::Display, str::FromStr};
+
+use clap::Parser;
+
+use crate::options::{GeneralOptions, ProjectOptions};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SortBy {
+    Name,
+    Afferent,
+    Efferent,
+    Instability,
+    Abstractness,
+    Distance,
+}
+
+impl FromStr for SortBy {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "afferent" => Ok(Self::Afferent),
+            "efferent" => Ok(Self::Efferent),
+            "instability" => Ok(Self::Instability),
+            "abstractness" => Ok(Self::Abstractness),
+            "distance" => Ok(Self::Distance),
+            _ => Err("Unrecognized sort order"),
+        }
+    }
+}
+
+impl Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Name => "name",
+            Self::Afferent => "afferent",
+            Self::Efferent => "efferent",
+            Self::Instability => "instability",
+            Self::Abstractness => "abstractness",
+            Self::Distance => "distance",
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err("Unrecognized output format"),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+        })
+    }
+}
+
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[group(id = "MetricsOptions")]
+pub struct Options {
+    #[command(flatten)]
+    pub general: GeneralOptions,
+
+    #[command(flatten)]
+    pub project: ProjectOptions,
+
+    /// The sorting order to use
+    /// (e.g. name, afferent, efferent, instability, abstractness, distance).
+    #[arg(long = "sort-by", default_value = "name")]
+    pub sort_by: SortBy,
+
+    /// Reverses the sorting order.
+    #[arg(long = "sort-reversed")]
+    pub sort_reversed: bool,
+
+    /// The output format to use
+    /// (e.g. text, json).
+    #[arg(long = "format", default_value = "text")]
+    pub format: OutputFormat,
+
+    /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
+    #[arg(long = "cfg-test")]
+    pub cfg_test: bool,
+}
@@ -0,0 +1,60 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying module metrics as JSON.
+
+use json::JsonValue;
+
+use crate::command::metrics::module_metrics::ModuleMetrics;
+
+const INDENTATION: u16 = 4;
+
+pub struct JsonPrinter<'a> {
+    crate_name: &'a str,
+}
+
+impl<'a> JsonPrinter<'a> {
+    pub fn new(crate_name: &'a str) -> Self {
+        Self { crate_name }
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn std::io::Write,
+        metrics: &[ModuleMetrics],
+    ) -> Result<(), anyhow::Error> {
+        let modules: Vec<JsonValue> = metrics
+            .iter()
+            .map(|metrics| {
+                json::object! {
+                    "path": metrics.path.clone(),
+                    "afferent_coupling": metrics.afferent,
+                    "efferent_coupling": metrics.efferent,
+                    "traits": metrics.traits,
+                    "adts": metrics.adts,
+                    "instability": metrics.instability().map(round),
+                    "abstractness": metrics.abstractness().map(round),
+                    "distance": metrics.distance().map(round),
+                }
+            })
+            .collect();
+
+        let root = json::object! {
+            "crate": self.crate_name,
+            "modules": modules,
+        };
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+}
+
+/// Rounds the ratio to a fixed number of decimal places,
+/// keeping the output stable across platforms' floating-point quirks.
+fn round(value: f64) -> f64 {
+    const FACTOR: f64 = 1000.0;
+
+    (value * FACTOR).round() / FACTOR
+}
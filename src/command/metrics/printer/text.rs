@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying module metrics as a human-readable table.
+
+use crate::{command::metrics::module_metrics::ModuleMetrics, utils::table_row};
+
+const HEADERS: [&str; 8] = ["Module", "Ca", "Ce", "Traits", "ADTs", "I", "A", "D"];
+
+pub struct TextPrinter;
+
+impl TextPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fmt(
+        &self,
+        f: &mut dyn std::io::Write,
+        metrics: &[ModuleMetrics],
+    ) -> Result<(), anyhow::Error> {
+        let rows: Vec<[String; 8]> = metrics
+            .iter()
+            .map(|metrics| {
+                [
+                    metrics.path.clone(),
+                    metrics.afferent.to_string(),
+                    metrics.efferent.to_string(),
+                    metrics.traits.to_string(),
+                    metrics.adts.to_string(),
+                    ratio(metrics.instability()),
+                    ratio(metrics.abstractness()),
+                    ratio(metrics.distance()),
+                ]
+            })
+            .collect();
+
+        let mut widths: Vec<usize> = HEADERS.iter().map(|header| header.len()).collect();
+
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let header_row: Vec<String> = HEADERS.iter().map(|header| header.to_string()).collect();
+
+        writeln!(f, "{}", table_row(&header_row, &widths))?;
+
+        let separators: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+        writeln!(f, "{}", table_row(&separators, &widths))?;
+
+        for row in &rows {
+            writeln!(f, "{}", table_row(row, &widths))?;
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Ca: afferent coupling, Ce: efferent coupling, I: instability, A: abstractness, D: distance from main sequence"
+        )?;
+
+        Ok(())
+    }
+}
+
+fn ratio(value: Option<f64>) -> String {
+    match value {
+        Some(value) => format!("{value:.2}"),
+        None => "-".to_owned(),
+    }
+}
@@ -11,7 +11,10 @@ use ra_ap_vfs::{self as vfs};
 
 use crate::analyzer::{self, LoadOptions};
 
-use super::{options::Options, printer::Printer};
+use super::{
+    options::{Options, OutputFormat},
+    printer::{JsonPrinter, SarifPrinter, TextPrinter},
+};
 
 use super::scanner::Scanner;
 
@@ -48,8 +51,21 @@ impl Command {
         orphans.sort_by_cached_key(|orphan| orphan.file_path.clone());
 
         let mut stdout = std::io::stdout();
-        let printer = Printer::new(&self.options);
-        printer.fmt(&mut stdout, &orphans[..])?;
+
+        match self.options.format {
+            OutputFormat::Text => {
+                let printer = TextPrinter::new(&self.options);
+                printer.fmt(&mut stdout, &orphans[..])?;
+            }
+            OutputFormat::Json => {
+                let printer = JsonPrinter::new(&self.options, &crate_name);
+                printer.fmt(&mut stdout, &orphans[..])?;
+            }
+            OutputFormat::Sarif => {
+                let printer = SarifPrinter::new(&self.options);
+                printer.fmt(&mut stdout, &orphans[..])?;
+            }
+        }
 
         if orphans.is_empty() {
             Ok(())
@@ -65,6 +81,8 @@ impl Command {
         LoadOptions {
             cfg_test: self.options.cfg_test,
             sysroot: false,
+            workspace: false,
+            all_targets: false,
         }
     }
 }
@@ -2,10 +2,42 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::{fmt::Display, str::FromStr};
+
 use clap::Parser;
 
 use crate::options::{GeneralOptions, ProjectOptions};
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+impl FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            _ => Err("Unrecognized output format"),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Sarif => "sarif",
+        })
+    }
+}
+
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
 #[group(id = "OrphansOptions")]
 pub struct Options {
@@ -19,6 +51,11 @@ pub struct Options {
     #[arg(long = "deny")]
     pub deny: bool,
 
+    /// The output format to use
+    /// (e.g. text, json, sarif).
+    #[arg(long = "format", default_value = "text")]
+    pub format: OutputFormat,
+
     /// Analyze with `#[cfg(test)]` enabled (i.e as if built via `cargo test`).
     #[arg(long = "cfg-test")]
     pub cfg_test: bool,
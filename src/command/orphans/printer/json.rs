@@ -0,0 +1,52 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying orphans as JSON.
+
+use json::JsonValue;
+
+use crate::command::orphans::{options::Options, orphan::Orphan};
+
+use super::{prefix_path, relative_path};
+
+const INDENTATION: u16 = 4;
+
+pub struct JsonPrinter<'a> {
+    options: &'a Options,
+    crate_name: &'a str,
+}
+
+impl<'a> JsonPrinter<'a> {
+    pub fn new(options: &'a Options, crate_name: &'a str) -> Self {
+        Self {
+            options,
+            crate_name,
+        }
+    }
+
+    pub fn fmt(&self, f: &mut dyn std::io::Write, orphans: &[Orphan]) -> Result<(), anyhow::Error> {
+        let prefix_path = prefix_path(self.options);
+
+        let orphans: Vec<JsonValue> = orphans
+            .iter()
+            .map(|orphan| {
+                json::object! {
+                    "name": orphan.name.clone(),
+                    "file_path": relative_path(&orphan.file_path, &prefix_path),
+                    "parent_module_path": orphan.parent_module_path.clone(),
+                    "parent_file_path": relative_path(&orphan.parent_file_path, &prefix_path),
+                }
+            })
+            .collect();
+
+        let root = json::object! {
+            "crate": self.crate_name,
+            "orphans": orphans,
+        };
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying orphans as a SARIF log.
+//!
+//! See: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use json::JsonValue;
+
+use crate::command::orphans::{options::Options, orphan::Orphan};
+
+use super::{prefix_path, relative_path};
+
+const INDENTATION: u16 = 4;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+const RULE_ID: &str = "orphaned-module";
+
+// Paths are printed relative to the crate's manifest directory,
+// which SARIF consumers are expected to resolve against the source root:
+const URI_BASE_ID: &str = "%SRCROOT%";
+
+pub struct SarifPrinter<'a> {
+    options: &'a Options,
+}
+
+impl<'a> SarifPrinter<'a> {
+    pub fn new(options: &'a Options) -> Self {
+        Self { options }
+    }
+
+    pub fn fmt(&self, f: &mut dyn std::io::Write, orphans: &[Orphan]) -> Result<(), anyhow::Error> {
+        let prefix_path = prefix_path(self.options);
+
+        let level = if self.options.deny {
+            "error"
+        } else {
+            "warning"
+        };
+
+        let results: Vec<JsonValue> = orphans
+            .iter()
+            .map(|orphan| {
+                let file_path = relative_path(&orphan.file_path, &prefix_path);
+                let parent_file_path = relative_path(&orphan.parent_file_path, &prefix_path);
+
+                let name = &orphan.name;
+                let parent_module_path = &orphan.parent_module_path;
+
+                let location = json::object! {
+                    "physicalLocation": json::object! {
+                        "artifactLocation": json::object! {
+                            "uri": file_path.clone(),
+                            "uriBaseId": URI_BASE_ID,
+                        },
+                    },
+                };
+
+                let related_location = json::object! {
+                    "id": 0,
+                    "message": json::object! {
+                        "text": format!("parent module `{parent_module_path}`"),
+                    },
+                    "physicalLocation": json::object! {
+                        "artifactLocation": json::object! {
+                            "uri": parent_file_path,
+                            "uriBaseId": URI_BASE_ID,
+                        },
+                    },
+                };
+
+                json::object! {
+                    "ruleId": RULE_ID,
+                    "ruleIndex": 0,
+                    "level": level,
+                    "message": json::object! {
+                        "text": format!(
+                            "orphaned module `{name}` at {file_path}, \
+                            consider loading `{name}` from module `{parent_module_path}` \
+                            via `mod {name};`"
+                        ),
+                    },
+                    "locations": vec![location],
+                    "relatedLocations": vec![related_location],
+                }
+            })
+            .collect();
+
+        let rule = json::object! {
+            "id": RULE_ID,
+            "name": "OrphanedModule",
+            "shortDescription": json::object! {
+                "text": "Orphaned module",
+            },
+            "fullDescription": json::object! {
+                "text": "Source file within a crate's directory that is not loaded as a module.",
+            },
+            "defaultConfiguration": json::object! {
+                "level": level,
+            },
+        };
+
+        let driver = json::object! {
+            "name": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "informationUri": env!("CARGO_PKG_HOMEPAGE"),
+            "rules": vec![rule],
+        };
+
+        let run = json::object! {
+            "tool": json::object! {
+                "driver": driver,
+            },
+            "results": results,
+        };
+
+        let root = json::object! {
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": vec![run],
+        };
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+}
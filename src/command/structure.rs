@@ -5,8 +5,10 @@
 pub mod options;
 
 pub(super) mod command;
+pub(super) mod diff;
 pub(super) mod filter;
 pub(crate) mod printer;
+pub(super) mod stats;
 pub(super) mod theme;
 
 type Node = crate::item::Item;
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::fmt::Write;
+use std::{fmt::Write, path::Path};
 
 use hir::db::HirDatabase;
 use ra_ap_hir::{self as hir};
@@ -10,9 +10,23 @@ use ra_ap_ide::{self as ide};
 
 use clap::Parser;
 
-use crate::{analyzer::LoadOptions, tree::TreeBuilder};
-
-use super::{filter::Filter, options::Options, printer::Printer};
+use crate::{
+    analyzer::{LoadOptions, load_workspace},
+    options::ProjectOptions,
+    tree::{Tree, TreeBuilder},
+};
+
+use super::{
+    Node,
+    diff::{SnapshotBuilder, TreeDiff, TreeSnapshot},
+    filter::Filter,
+    options::{Options, OutputFormat},
+    printer::{
+        DiffJsonPrinter, DiffTextPrinter, JsonPrinter, StatsJsonPrinter, StatsTextPrinter,
+        TextPrinter,
+    },
+    stats::StatsBuilder,
+};
 
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
 pub struct Command {
@@ -36,34 +50,127 @@ impl Command {
     ) -> anyhow::Result<()> {
         let db: &dyn HirDatabase = db;
 
-        tracing::trace!("Building tree ...");
+        let tree = self.tree(krate, db, edition)?;
 
-        let builder = TreeBuilder::new(db, krate);
-        let tree = builder.build()?;
+        let mut output = String::new();
 
-        tracing::trace!("Filtering tree ...");
+        if let Some(manifest_path) = &self.options.diff {
+            let new_snapshot = SnapshotBuilder::new(db, edition).build(&tree);
+            let old_snapshot = self.snapshot_against(manifest_path)?;
 
-        let filter = Filter::new(&self.options, krate, db, edition);
+            tracing::trace!("Comparing trees ...");
 
-        let tree = filter.filter(&tree)?;
+            let diff = TreeDiff::between(&old_snapshot, &new_snapshot);
 
-        tracing::trace!("Printing tree ...");
+            tracing::trace!("Printing diff ...");
 
-        let mut output = String::new();
-        writeln!(&mut output)?;
+            match self.options.format {
+                OutputFormat::Text => {
+                    let printer = DiffTextPrinter::new();
+                    printer.fmt(&mut output, &diff)?;
+                }
+                OutputFormat::Json => {
+                    let printer = DiffJsonPrinter::new();
+                    printer.fmt(&mut output, &diff)?;
+                }
+            }
 
-        let printer = Printer::new(&self.options, db, edition);
-        printer.fmt(&mut output, &tree)?;
+            print!("{output}");
+
+            return Ok(());
+        }
+
+        if self.options.stats {
+            tracing::trace!("Aggregating stats ...");
+
+            let builder = StatsBuilder::new(&self.options, db, edition);
+            let stats = builder.build(&tree);
+
+            tracing::trace!("Printing stats ...");
+
+            match self.options.format {
+                OutputFormat::Text => {
+                    let printer = StatsTextPrinter::new();
+                    printer.fmt(&mut output, &stats)?;
+                }
+                OutputFormat::Json => {
+                    let printer = StatsJsonPrinter::new();
+                    printer.fmt(&mut output, &stats)?;
+                }
+            }
+
+            print!("{output}");
+
+            return Ok(());
+        }
+
+        tracing::trace!("Printing tree ...");
+
+        match self.options.format {
+            OutputFormat::Text => {
+                writeln!(&mut output)?;
+
+                let printer = TextPrinter::new(&self.options, db, edition);
+                printer.fmt(&mut output, &tree)?;
+            }
+            OutputFormat::Json => {
+                let printer = JsonPrinter::new(&self.options, db, edition);
+                printer.fmt(&mut output, &tree)?;
+            }
+        }
 
         print!("{output}");
 
         Ok(())
     }
 
+    fn tree(
+        &self,
+        krate: hir::Crate,
+        db: &dyn HirDatabase,
+        edition: ide::Edition,
+    ) -> anyhow::Result<Tree<Node>> {
+        tracing::trace!("Building tree ...");
+
+        let builder = TreeBuilder::new(db, krate);
+        let tree = builder.build()?;
+
+        tracing::trace!("Filtering tree ...");
+
+        let filter = Filter::new(&self.options, krate, db, edition);
+
+        filter.filter(&tree)
+    }
+
+    /// Loads the crate of the checkout at `manifest_path` and takes a snapshot of its tree.
+    fn snapshot_against(&self, manifest_path: &Path) -> anyhow::Result<TreeSnapshot> {
+        tracing::trace!("Loading crate to compare against ...");
+
+        let project_options = ProjectOptions {
+            manifest_path: manifest_path.to_owned(),
+            ..self.options.project.clone()
+        };
+
+        let (krate, host, _vfs, edition) = load_workspace(
+            &self.options.general,
+            &project_options,
+            &self.load_options(),
+        )?;
+        let db: &dyn HirDatabase = host.raw_database();
+
+        // The crates live in separate databases, hence the need for switching between them:
+        hir::attach_db_allow_change(db, || {
+            let tree = self.tree(krate, db, edition)?;
+            Ok(SnapshotBuilder::new(db, edition).build(&tree))
+        })
+    }
+
     pub fn load_options(&self) -> LoadOptions {
         LoadOptions {
             cfg_test: self.options.cfg_test,
             sysroot: false,
+            workspace: false,
+            all_targets: false,
         }
     }
 }
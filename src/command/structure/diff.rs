@@ -0,0 +1,251 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Differences between the trees of two checkouts of a crate.
+//!
+//! Trees loaded from different checkouts live in different databases,
+//! so their items can only be matched up by their paths (or names, for moved items).
+
+use std::collections::{BTreeMap, HashMap};
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use crate::{item::ItemVisibility, tree::Tree};
+
+use super::Node;
+
+/// An item of a tree, detached from its database.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ItemSnapshot {
+    pub path: String,
+    pub name: String,
+    pub kind: String,
+    /// The path of the item's parent (e.g. its module, or the type owning a method).
+    pub parent: String,
+    pub visibility: ItemVisibility,
+    /// The path of the module containing the item (relative to its crate),
+    /// which its `pub(self)` and `pub(super)` visibilities are relative to.
+    pub module: String,
+}
+
+/// The items of a tree (excluding its crate root), keyed by their paths.
+pub(crate) type TreeSnapshot = BTreeMap<String, ItemSnapshot>;
+
+pub(crate) struct SnapshotBuilder<'a> {
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> SnapshotBuilder<'a> {
+    pub fn new(db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self { db, edition }
+    }
+
+    pub fn build(&self, tree: &Tree<Node>) -> TreeSnapshot {
+        let mut snapshot = TreeSnapshot::new();
+
+        self.visit_subtrees(tree, &mut snapshot);
+
+        snapshot
+    }
+
+    fn visit_subtrees(&self, tree: &Tree<Node>, snapshot: &mut TreeSnapshot) {
+        let parent = tree.node.display_path(self.db, self.edition);
+
+        for subtree in &tree.subtrees {
+            let node = &subtree.node;
+
+            let item = ItemSnapshot {
+                path: node.display_path(self.db, self.edition),
+                name: node.display_name(self.db, self.edition),
+                kind: node.kind_display_name(self.db, self.edition).to_string(),
+                parent: parent.clone(),
+                visibility: node.visibility(self.db, self.edition),
+                module: self.module_path(node),
+            };
+
+            snapshot.insert(item.path.clone(), item);
+
+            self.visit_subtrees(subtree, snapshot);
+        }
+    }
+
+    fn module_path(&self, node: &Node) -> String {
+        node.hir
+            .module(self.db)
+            .and_then(|module| hir::ModuleDef::Module(module).canonical_path(self.db, self.edition))
+            .unwrap_or_default()
+    }
+}
+
+/// How an item's visibility changed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum VisibilityChange {
+    Widened,
+    Narrowed,
+    /// The item is visible within an unrelated module (e.g. `pub(in crate::a)` -> `pub(in crate::b)`).
+    Changed,
+}
+
+impl VisibilityChange {
+    fn between(old: &ItemSnapshot, new: &ItemSnapshot) -> Option<Self> {
+        if old.visibility == new.visibility {
+            return None;
+        }
+
+        let old_scope = VisibilityScope::new(&old.visibility, &old.module);
+        let new_scope = VisibilityScope::new(&new.visibility, &new.module);
+
+        if old_scope == new_scope {
+            None
+        } else if new_scope.contains(&old_scope) {
+            Some(Self::Widened)
+        } else if old_scope.contains(&new_scope) {
+            Some(Self::Narrowed)
+        } else {
+            Some(Self::Changed)
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Widened => "widened",
+            Self::Narrowed => "narrowed",
+            Self::Changed => "changed",
+        }
+    }
+}
+
+/// The scope an item is visible within.
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum VisibilityScope<'a> {
+    Everywhere,
+    /// The module (as path segments relative to the crate root) and its descendants.
+    Module(Vec<&'a str>),
+}
+
+impl<'a> VisibilityScope<'a> {
+    fn new(visibility: &'a ItemVisibility, module: &'a str) -> Self {
+        let segments = |path: &'a str| -> Vec<&'a str> {
+            path.split("::")
+                .filter(|segment| !segment.is_empty())
+                .collect()
+        };
+
+        match visibility {
+            ItemVisibility::Public => Self::Everywhere,
+            ItemVisibility::Crate => Self::Module(vec![]),
+            ItemVisibility::Module(path) => Self::Module(segments(path)),
+            ItemVisibility::Private => Self::Module(segments(module)),
+            ItemVisibility::Super => {
+                let mut segments = segments(module);
+                segments.pop();
+                Self::Module(segments)
+            }
+        }
+    }
+
+    fn contains(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Everywhere, _) => true,
+            (Self::Module(_), Self::Everywhere) => false,
+            (Self::Module(outer), Self::Module(inner)) => inner.starts_with(outer),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct MovedItem {
+    pub old: ItemSnapshot,
+    pub new: ItemSnapshot,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ChangedVisibility {
+    pub old: ItemSnapshot,
+    pub new: ItemSnapshot,
+    pub change: VisibilityChange,
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub(crate) struct TreeDiff {
+    pub added: Vec<ItemSnapshot>,
+    pub removed: Vec<ItemSnapshot>,
+    pub moved: Vec<MovedItem>,
+    /// Visibility changes of items that were either kept in place, or moved.
+    pub changed_visibility: Vec<ChangedVisibility>,
+}
+
+impl TreeDiff {
+    /// Compares the `old` snapshot against the `new` one.
+    ///
+    /// Items are matched up by their paths first. Any remaining items
+    /// of the same name and kind are considered to have been moved.
+    pub fn between(old: &TreeSnapshot, new: &TreeSnapshot) -> Self {
+        let mut diff = Self::default();
+
+        let mut removed: HashMap<(&str, &str), Vec<&ItemSnapshot>> = HashMap::new();
+
+        for (path, old_item) in old {
+            if let Some(new_item) = new.get(path) {
+                diff.push_visibility_change(old_item, new_item);
+            } else {
+                removed
+                    .entry((&old_item.name, &old_item.kind))
+                    .or_default()
+                    .push(old_item);
+            }
+        }
+
+        for (path, new_item) in new {
+            if old.contains_key(path) {
+                continue;
+            }
+
+            let candidates = removed
+                .get_mut(&(new_item.name.as_str(), new_item.kind.as_str()))
+                .filter(|candidates| !candidates.is_empty());
+
+            if let Some(candidates) = candidates {
+                let old_item = candidates.remove(0);
+
+                diff.moved.push(MovedItem {
+                    old: old_item.clone(),
+                    new: new_item.clone(),
+                });
+
+                diff.push_visibility_change(old_item, new_item);
+            } else {
+                diff.added.push(new_item.clone());
+            }
+        }
+
+        diff.removed = removed.into_values().flatten().cloned().collect();
+        diff.removed.sort_by(|lhs, rhs| lhs.path.cmp(&rhs.path));
+
+        diff.changed_visibility
+            .sort_by(|lhs, rhs| lhs.new.path.cmp(&rhs.new.path));
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.moved.is_empty()
+            && self.changed_visibility.is_empty()
+    }
+
+    fn push_visibility_change(&mut self, old: &ItemSnapshot, new: &ItemSnapshot) {
+        if let Some(change) = VisibilityChange::between(old, new) {
+            self.changed_visibility.push(ChangedVisibility {
+                old: old.clone(),
+                new: new.clone(),
+                change,
+            });
+        }
+    }
+}
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, path::PathBuf, str::FromStr};
 
 use clap::Parser;
 
@@ -38,6 +38,33 @@ impl Display for SortBy {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err("Unrecognized output format"),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+        })
+    }
+}
+
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
 #[group(id = "GenerateTreeOptions")]
 pub struct Options {
@@ -59,6 +86,21 @@ pub struct Options {
     #[arg(long = "sort-reversed")]
     pub sort_reversed: bool,
 
+    /// The output format to use
+    /// (e.g. text, json).
+    #[arg(long = "format", default_value = "text")]
+    pub format: OutputFormat,
+
+    /// Print the number of items per module by kind and visibility,
+    /// with each module's totals including its sub-modules, instead of the tree.
+    #[arg(long = "stats")]
+    pub stats: bool,
+
+    /// Compare the tree against that of another checkout's Cargo.toml,
+    /// printing added, removed and moved items, as well as visibility changes, instead of the tree.
+    #[arg(long = "diff", conflicts_with_all = ["stats", "project_json"])]
+    pub diff: Option<PathBuf>,
+
     /// Focus the graph on a particular path or use-tree's environment,
     /// e.g. "foo::bar::{self, baz, blee::*}".
     #[arg(long = "focus-on")]
@@ -0,0 +1,165 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printers for displaying the differences between two trees.
+
+use std::fmt;
+
+use json::JsonValue;
+use yansi::Paint as _;
+
+use crate::command::structure::{
+    diff::{ItemSnapshot, TreeDiff},
+    theme::styles,
+};
+
+const INDENTATION: u16 = 4;
+
+pub struct DiffTextPrinter;
+
+impl DiffTextPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, diff: &TreeDiff) -> Result<(), anyhow::Error> {
+        let styles = styles().diff;
+
+        writeln!(f)?;
+
+        if diff.is_empty() {
+            writeln!(f, "No changes found.")?;
+
+            return Ok(());
+        }
+
+        let added: Vec<String> = diff
+            .added
+            .iter()
+            .map(|item| format!("+ {}", Self::item(item)))
+            .collect();
+
+        let removed: Vec<String> = diff
+            .removed
+            .iter()
+            .map(|item| format!("- {}", Self::item(item)))
+            .collect();
+
+        let moved: Vec<String> = diff
+            .moved
+            .iter()
+            .map(|moved| {
+                format!(
+                    "~ {kind} {name}: {old} -> {new}",
+                    kind = moved.new.kind,
+                    name = moved.new.name,
+                    old = moved.old.parent,
+                    new = moved.new.parent,
+                )
+            })
+            .collect();
+
+        let changed_visibility: Vec<String> = diff
+            .changed_visibility
+            .iter()
+            .map(|changed| {
+                format!(
+                    "~ {kind} {path}: {old} -> {new} ({change})",
+                    kind = changed.new.kind,
+                    path = changed.new.path,
+                    old = changed.old.visibility,
+                    new = changed.new.visibility,
+                    change = changed.change.display_name(),
+                )
+            })
+            .collect();
+
+        let sections = [
+            ("Added items", added, styles.added),
+            ("Removed items", removed, styles.removed),
+            ("Moved items", moved, styles.changed),
+            ("Visibility changes", changed_visibility, styles.changed),
+        ];
+
+        for (heading, lines, style) in sections {
+            if lines.is_empty() {
+                continue;
+            }
+
+            let count = lines.len();
+            writeln!(f, "{} ({count}):", heading.paint(styles.heading))?;
+            writeln!(f)?;
+
+            for line in lines {
+                writeln!(f, "{}", line.paint(style))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+
+    fn item(item: &ItemSnapshot) -> String {
+        format!("{} {}: {}", item.kind, item.path, item.visibility)
+    }
+}
+
+pub struct DiffJsonPrinter;
+
+impl DiffJsonPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, diff: &TreeDiff) -> Result<(), anyhow::Error> {
+        let added: Vec<JsonValue> = diff.added.iter().map(Self::item).collect();
+        let removed: Vec<JsonValue> = diff.removed.iter().map(Self::item).collect();
+
+        let moved: Vec<JsonValue> = diff
+            .moved
+            .iter()
+            .map(|moved| {
+                json::object! {
+                    "kind": moved.new.kind.clone(),
+                    "old_path": moved.old.path.clone(),
+                    "new_path": moved.new.path.clone(),
+                }
+            })
+            .collect();
+
+        let changed_visibility: Vec<JsonValue> = diff
+            .changed_visibility
+            .iter()
+            .map(|changed| {
+                json::object! {
+                    "path": changed.new.path.clone(),
+                    "kind": changed.new.kind.clone(),
+                    "old_visibility": changed.old.visibility.to_string(),
+                    "new_visibility": changed.new.visibility.to_string(),
+                    "change": changed.change.display_name(),
+                }
+            })
+            .collect();
+
+        let root = json::object! {
+            "added": added,
+            "removed": removed,
+            "moved": moved,
+            "visibility_changes": changed_visibility,
+        };
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+
+    fn item(item: &ItemSnapshot) -> JsonValue {
+        json::object! {
+            "path": item.path.clone(),
+            "kind": item.kind.clone(),
+            "visibility": item.visibility.to_string(),
+        }
+    }
+}
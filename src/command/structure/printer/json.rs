@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printer for displaying module structure as a JSON tree.
+
+use std::fmt;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use json::JsonValue;
+
+use crate::{
+    command::structure::{Node, options::Options},
+    tree::Tree,
+};
+
+use super::sorted_subtrees;
+
+const INDENTATION: u16 = 4;
+
+pub struct JsonPrinter<'a> {
+    options: &'a Options,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> JsonPrinter<'a> {
+    pub fn new(options: &'a Options, db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self {
+            options,
+            db,
+            edition,
+        }
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, tree: &Tree<Node>) -> Result<(), anyhow::Error> {
+        let root = self.tree(tree);
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+
+    fn tree(&self, tree: &Tree<Node>) -> JsonValue {
+        let node = &tree.node;
+        let attrs = node.attrs(self.db, self.edition);
+
+        let cfgs: Vec<String> = attrs.cfgs.iter().map(|cfg| cfg.to_string()).collect();
+
+        let children: Vec<JsonValue> = sorted_subtrees(tree, self.options, self.db, self.edition)
+            .iter()
+            .map(|subtree| self.tree(subtree))
+            .collect();
+
+        json::object! {
+            "path": node.display_path(self.db, self.edition),
+            "name": node.display_name(self.db, self.edition),
+            "kind": node.kind_display_name(self.db, self.edition).to_string(),
+            "visibility": node.visibility(self.db, self.edition).to_string(),
+            "attrs": json::object! {
+                "cfgs": cfgs,
+                "test": attrs.test.is_some(),
+            },
+            "children": children,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Printers for displaying per-module item statistics.
+
+use std::fmt;
+
+use json::JsonValue;
+
+use crate::{
+    command::structure::stats::{Counts, ModuleStats},
+    utils::table_row,
+};
+
+const INDENTATION: u16 = 4;
+
+const HEADERS: [&str; 11] = [
+    "Module",
+    "Items",
+    "Own",
+    "Fns",
+    "Tests",
+    "Structs",
+    "Enums",
+    "Unions",
+    "Traits",
+    "pub",
+    "pub(crate)",
+];
+
+pub struct StatsTextPrinter;
+
+impl StatsTextPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, stats: &ModuleStats) -> Result<(), anyhow::Error> {
+        let mut rows: Vec<Vec<String>> = vec![];
+        Self::collect_rows(stats, &mut rows);
+
+        let mut widths: Vec<usize> = HEADERS.iter().map(|header| header.len()).collect();
+
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let header_row: Vec<String> = HEADERS.iter().map(|header| header.to_string()).collect();
+
+        writeln!(f, "{}", table_row(&header_row, &widths))?;
+
+        let separators: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+        writeln!(f, "{}", table_row(&separators, &widths))?;
+
+        for row in &rows {
+            writeln!(f, "{}", table_row(row, &widths))?;
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Counts include the items of sub-modules, except for \"Own\" (i.e. the module's own items)."
+        )?;
+
+        Ok(())
+    }
+
+    fn collect_rows(stats: &ModuleStats, rows: &mut Vec<Vec<String>>) {
+        let total = &stats.total;
+
+        rows.push(vec![
+            stats.path.clone(),
+            total.items.to_string(),
+            stats.own.items.to_string(),
+            total.functions.to_string(),
+            total.tests.to_string(),
+            total.structs.to_string(),
+            total.enums.to_string(),
+            total.unions.to_string(),
+            total.traits.to_string(),
+            total.pub_global.to_string(),
+            total.pub_crate.to_string(),
+        ]);
+
+        for submodule in &stats.submodules {
+            Self::collect_rows(submodule, rows);
+        }
+    }
+}
+
+pub struct StatsJsonPrinter;
+
+impl StatsJsonPrinter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fmt(&self, f: &mut dyn fmt::Write, stats: &ModuleStats) -> Result<(), anyhow::Error> {
+        let root = Self::module(stats);
+
+        writeln!(f, "{}", root.pretty(INDENTATION))?;
+
+        Ok(())
+    }
+
+    fn module(stats: &ModuleStats) -> JsonValue {
+        let submodules: Vec<JsonValue> = stats.submodules.iter().map(Self::module).collect();
+
+        json::object! {
+            "path": stats.path.clone(),
+            "own": Self::counts(&stats.own),
+            "total": Self::counts(&stats.total),
+            "submodules": submodules,
+        }
+    }
+
+    fn counts(counts: &Counts) -> JsonValue {
+        json::object! {
+            "items": counts.items,
+            "functions": counts.functions,
+            "tests": counts.tests,
+            "structs": counts.structs,
+            "enums": counts.enums,
+            "unions": counts.unions,
+            "traits": counts.traits,
+            "pub": counts.pub_global,
+            "pub_crate": counts.pub_crate,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Statistics on the number of items per module, by kind and visibility.
+
+use std::ops::AddAssign;
+
+use hir::db::HirDatabase;
+use ra_ap_hir::{self as hir};
+use ra_ap_ide::Edition;
+
+use crate::{item::ItemVisibility, tree::Tree};
+
+use super::{Node, options::Options, printer::sorted_subtrees};
+
+/// The number of items, by kind and visibility.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub(crate) struct Counts {
+    pub items: usize,
+    pub functions: usize,
+    pub tests: usize,
+    pub structs: usize,
+    pub enums: usize,
+    pub unions: usize,
+    pub traits: usize,
+    pub pub_global: usize,
+    pub pub_crate: usize,
+}
+
+impl AddAssign for Counts {
+    fn add_assign(&mut self, rhs: Self) {
+        self.items += rhs.items;
+        self.functions += rhs.functions;
+        self.tests += rhs.tests;
+        self.structs += rhs.structs;
+        self.enums += rhs.enums;
+        self.unions += rhs.unions;
+        self.traits += rhs.traits;
+        self.pub_global += rhs.pub_global;
+        self.pub_crate += rhs.pub_crate;
+    }
+}
+
+/// The statistics of a module and (recursively) its sub-modules.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct ModuleStats {
+    pub path: String,
+    /// The counts of items owned by the module itself (excluding its sub-modules).
+    pub own: Counts,
+    /// The counts of items owned by the module or any of its sub-modules.
+    pub total: Counts,
+    pub submodules: Vec<ModuleStats>,
+}
+
+pub(crate) struct StatsBuilder<'a> {
+    options: &'a Options,
+    db: &'a dyn HirDatabase,
+    edition: Edition,
+}
+
+impl<'a> StatsBuilder<'a> {
+    pub fn new(options: &'a Options, db: &'a dyn HirDatabase, edition: Edition) -> Self {
+        Self {
+            options,
+            db,
+            edition,
+        }
+    }
+
+    /// Aggregates the tree's items per module, rolling up each module's totals to its parent.
+    pub fn build(&self, tree: &Tree<Node>) -> ModuleStats {
+        let mut own = Counts::default();
+        let mut submodules = vec![];
+
+        self.visit_subtrees(tree, &mut own, &mut submodules);
+
+        let mut total = own;
+        for submodule in &submodules {
+            total += submodule.total;
+        }
+
+        ModuleStats {
+            path: tree.node.display_path(self.db, self.edition),
+            own,
+            total,
+            submodules,
+        }
+    }
+
+    fn visit_subtrees(
+        &self,
+        tree: &Tree<Node>,
+        own: &mut Counts,
+        submodules: &mut Vec<ModuleStats>,
+    ) {
+        for subtree in sorted_subtrees(tree, self.options, self.db, self.edition) {
+            if let hir::ModuleDef::Module(_) = subtree.node.hir {
+                submodules.push(self.build(&subtree));
+            } else {
+                *own += self.counts(&subtree.node);
+
+                // Items nested within other items (e.g. methods) belong to the same module:
+                self.visit_subtrees(&subtree, own, submodules);
+            }
+        }
+    }
+
+    fn counts(&self, node: &Node) -> Counts {
+        let mut counts = Counts {
+            items: 1,
+            ..Counts::default()
+        };
+
+        match node.hir {
+            hir::ModuleDef::Function(_) => {
+                counts.functions = 1;
+
+                if node.attrs(self.db, self.edition).test.is_some() {
+                    counts.tests = 1;
+                }
+            }
+            hir::ModuleDef::Adt(hir::Adt::Struct(_)) => counts.structs = 1,
+            hir::ModuleDef::Adt(hir::Adt::Enum(_)) => counts.enums = 1,
+            hir::ModuleDef::Adt(hir::Adt::Union(_)) => counts.unions = 1,
+            hir::ModuleDef::Trait(_) => counts.traits = 1,
+            _ => {}
+        }
+
+        match node.visibility(self.db, self.edition) {
+            ItemVisibility::Public => counts.pub_global = 1,
+            ItemVisibility::Crate => counts.pub_crate = 1,
+            _ => {}
+        }
+
+        counts
+    }
+}
@@ -15,6 +15,14 @@ pub(crate) struct VisibilityStyles {
     pub pub_super: Style,
 }
 
+#[derive(Clone, Debug)]
+pub(crate) struct DiffStyles {
+    pub heading: Style,
+    pub added: Style,
+    pub removed: Style,
+    pub changed: Style,
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct Styles {
     pub kind: Style,
@@ -24,6 +32,7 @@ pub(crate) struct Styles {
     pub colon: Style,
     pub attr_chrome: Style,
     pub branch: Style,
+    pub diff: DiffStyles,
 }
 
 pub(crate) fn styles() -> Styles {
@@ -42,5 +51,11 @@ pub(crate) fn styles() -> Styles {
         colon: Style::default().dim(),
         attr_chrome: Style::default().dim(),
         branch: Style::default().dim(),
+        diff: DiffStyles {
+            heading: Style::default().fg(color_palette.blue).bold(),
+            added: Style::default().fg(color_palette.green),
+            removed: Style::default().fg(color_palette.red),
+            changed: Style::default().fg(color_palette.yellow),
+        },
     }
 }
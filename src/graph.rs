@@ -11,17 +11,23 @@ use crate::item::Item;
 mod builder;
 mod walker;
 
-pub(crate) use self::{builder::GraphBuilder, walker::GraphWalker};
+pub(crate) use self::{
+    builder::{BuildOptions, GraphBuilder},
+    walker::GraphWalker,
+};
 
 pub type Graph<N, E> = StableGraph<N, E>;
 
 pub type Node = Item;
 pub type Edge = Relationship;
 
-#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub enum Relationship {
     Uses,
     Owns,
+    Reexports,
+    Implements,
+    Calls,
 }
 
 impl Relationship {
@@ -29,8 +35,17 @@ impl Relationship {
         match self {
             Self::Uses => "uses",
             Self::Owns => "owns",
+            Self::Reexports => "re-exports",
+            Self::Implements => "implements",
+            Self::Calls => "calls",
         }
     }
+
+    /// Returns `true` if the relationship is a dependency (i.e. "uses", "re-exports" or "calls"),
+    /// rather than a structural one (i.e. "owns" or "implements").
+    pub fn is_dependency(&self) -> bool {
+        matches!(self, Self::Uses | Self::Reexports | Self::Calls)
+    }
 }
 
 impl fmt::Display for Relationship {
@@ -38,6 +53,9 @@ impl fmt::Display for Relationship {
         let name = match self {
             Self::Uses => "Uses",
             Self::Owns => "Owns",
+            Self::Reexports => "Reexports",
+            Self::Implements => "Implements",
+            Self::Calls => "Calls",
         };
         write!(f, "{name}")
     }
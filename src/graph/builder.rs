@@ -4,9 +4,10 @@
 
 use std::collections::{HashMap, HashSet};
 
-use hir::db::HirDatabase;
+use hir::{HasSource as _, db::HirDatabase};
 use ra_ap_hir::{self as hir};
 use ra_ap_ide::Edition;
+use ra_ap_syntax::{AstNode as _, ast};
 
 use petgraph::graph::{EdgeIndex, NodeIndex};
 
@@ -23,40 +24,83 @@ struct Dependency {
     target_hir: hir::ModuleDef,
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct BuildOptions {
+    /// Add "uses" edges for the items referenced within function bodies.
+    pub body_deps: bool,
+
+    /// Add "calls" edges for the functions called within function bodies.
+    pub calls: bool,
+}
+
 #[derive(Debug)]
 pub struct GraphBuilder<'a> {
     db: &'a dyn HirDatabase,
+    sema: hir::Semantics<'a, dyn HirDatabase>,
     edition: Edition,
-    krate: hir::Crate,
+    krates: Vec<hir::Crate>,
+    options: BuildOptions,
     graph: Graph<Node, Edge>,
     nodes: HashMap<hir::ModuleDef, NodeIndex>,
     edges: HashMap<(NodeIndex, Relationship, NodeIndex), EdgeIndex>,
 }
 
 impl<'a> GraphBuilder<'a> {
-    pub fn new(db: &'a dyn HirDatabase, edition: Edition, krate: hir::Crate) -> Self {
+    pub fn new(
+        db: &'a dyn HirDatabase,
+        edition: Edition,
+        krate: hir::Crate,
+        options: BuildOptions,
+    ) -> Self {
+        Self::with_crates(db, edition, vec![krate], options)
+    }
+
+    /// Creates a builder for a combined graph of multiple crates (e.g. a workspace's members),
+    /// connected by the "uses" edges between their items.
+    pub fn with_crates(
+        db: &'a dyn HirDatabase,
+        edition: Edition,
+        krates: Vec<hir::Crate>,
+        options: BuildOptions,
+    ) -> Self {
+        let sema = hir::Semantics::new_dyn(db);
         let graph = Graph::default();
         let nodes = HashMap::default();
         let edges = HashMap::default();
 
         Self {
             db,
+            sema,
             edition,
-            krate,
+            krates,
+            options,
             graph,
             nodes,
             edges,
         }
     }
 
-    pub fn build(mut self) -> anyhow::Result<(Graph<Node, Edge>, NodeIndex)> {
+    pub fn build(self) -> anyhow::Result<(Graph<Node, Edge>, NodeIndex)> {
+        let (graph, crate_node_idxs) = self.build_all()?;
+
+        Ok((graph, crate_node_idxs[0]))
+    }
+
+    /// Builds the graph, returning the indices of all of its crates' nodes.
+    pub fn build_all(mut self) -> anyhow::Result<(Graph<Node, Edge>, Vec<NodeIndex>)> {
         let _span = tracing::trace_span!("Scanning project...").entered();
 
-        let node_idx = self
-            .process_crate(self.krate)
-            .expect("graph node for crate root module");
+        let crate_node_idxs = self
+            .krates
+            .clone()
+            .into_iter()
+            .map(|krate| {
+                self.process_crate(krate)
+                    .expect("graph node for crate root module")
+            })
+            .collect();
 
-        Ok((self.graph, node_idx))
+        Ok((self.graph, crate_node_idxs))
     }
 
     fn process_crate(&mut self, crate_hir: hir::Crate) -> Option<NodeIndex> {
@@ -73,9 +117,17 @@ impl<'a> GraphBuilder<'a> {
 
         let node_idx = self.process_moduledef(module.into());
 
+        let mut blanket_impls: Vec<hir::Impl> = vec![];
+
         for impl_hir in hir::Impl::all_in_crate(self.db, crate_hir) {
             let impl_ty = impl_hir.self_ty(self.db);
 
+            // Blanket impls (e.g. `impl<T: Foo> Bar for T`) have no single type to attach to:
+            if impl_ty.as_type_param(self.db).is_some() {
+                blanket_impls.push(impl_hir);
+                continue;
+            }
+
             let impl_ty_hir = if let Some(adt_hir) = impl_ty.as_adt() {
                 Some(hir::ModuleDef::Adt(adt_hir))
             } else {
@@ -95,11 +147,93 @@ impl<'a> GraphBuilder<'a> {
             for impl_item_idx in self.process_impl(impl_hir) {
                 self.add_edge(impl_ty_idx, impl_item_idx, Edge::Owns);
             }
+
+            let Some(trait_hir) = impl_hir.trait_(self.db) else {
+                continue;
+            };
+
+            // Impls of built-in derives (e.g. `#[derive(Clone)]`) would clutter the graph
+            // with edges to `core` traits, so we skip them:
+            if self.is_builtin_derive(impl_hir, trait_hir) {
+                continue;
+            }
+
+            self.add_implements_edge(impl_ty_idx, trait_hir);
         }
 
+        self.process_blanket_impls(crate_hir, blanket_impls);
+
         node_idx
     }
 
+    /// Adds "implements" edges from each of the crate's types
+    /// to the traits it implements by virtue of a blanket impl.
+    fn process_blanket_impls(&mut self, crate_hir: hir::Crate, impls: Vec<hir::Impl>) {
+        let _span = tracing::trace_span!("blanket impls").entered();
+
+        if impls.is_empty() {
+            return;
+        }
+
+        let mut adts: Vec<(NodeIndex, hir::Adt)> = self
+            .nodes
+            .iter()
+            .filter_map(|(module_def_hir, node_idx)| match module_def_hir {
+                hir::ModuleDef::Adt(adt_hir)
+                    if adt_hir.module(self.db).krate(self.db) == crate_hir =>
+                {
+                    Some((*node_idx, *adt_hir))
+                }
+                _ => None,
+            })
+            .collect();
+
+        adts.sort_by_key(|(node_idx, _)| *node_idx);
+
+        // Only traits that are part of the graph need checking (and only once each):
+        let mut traits: Vec<hir::Trait> = vec![];
+
+        for impl_hir in impls {
+            let Some(trait_hir) = impl_hir.trait_(self.db) else {
+                continue;
+            };
+
+            if !self.nodes.contains_key(&hir::ModuleDef::Trait(trait_hir)) {
+                continue;
+            }
+
+            if !traits.contains(&trait_hir) {
+                traits.push(trait_hir);
+            }
+        }
+
+        for trait_hir in traits {
+            // Generic traits (e.g. `impl<T: Foo> Bar<u8> for T`) are implemented
+            // if the type implements them for any of their type arguments:
+            for &(adt_idx, adt_hir) in &adts {
+                if adt_hir.ty(self.db).has_any_impl(self.db, trait_hir, &[]) {
+                    self.add_implements_edge(adt_idx, trait_hir);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the impl was generated by one of the built-in derives
+    /// (i.e. has no source and implements a trait of a sysroot crate).
+    fn is_builtin_derive(&self, impl_hir: hir::Impl, trait_hir: hir::Trait) -> bool {
+        let trait_krate = trait_hir.module(self.db).krate(self.db);
+
+        impl_hir.source(self.db).is_none() && trait_krate.origin(self.db).is_lang()
+    }
+
+    fn add_implements_edge(&mut self, impl_ty_idx: NodeIndex, trait_hir: hir::Trait) {
+        let Some(trait_idx) = self.add_node_if_necessary(hir::ModuleDef::Trait(trait_hir)) else {
+            return;
+        };
+
+        self.add_edge(impl_ty_idx, trait_idx, Edge::Implements);
+    }
+
     fn process_impl(&mut self, impl_hir: hir::Impl) -> Vec<NodeIndex> {
         let _span = tracing::trace_span!("impl").entered();
 
@@ -206,7 +340,16 @@ impl<'a> GraphBuilder<'a> {
             }
         }
 
-        for (_name, scope_hir) in module_hir.scope(self.db, None) {
+        // Items imported via `pub use` get re-exported, rather than used
+        // (matched by name, as an item may also be imported privately under another name):
+        let public_scope: HashSet<(String, hir::ModuleDef)> =
+            analyzer::public_scope(module_hir, self.db, self.edition)
+                .into_iter()
+                .collect();
+
+        let mut reexports: Vec<hir::ModuleDef> = vec![];
+
+        for (name, scope_hir) in module_hir.scope(self.db, None) {
             let hir::ScopeDef::ModuleDef(scope_module_hir) = scope_hir else {
                 // Skip everything but module-defs:
                 continue;
@@ -218,7 +361,23 @@ impl<'a> GraphBuilder<'a> {
                 continue;
             }
 
-            dependencies_callback(scope_module_hir);
+            let name = name.display(self.db, self.edition).to_string();
+
+            if public_scope.contains(&(name, scope_module_hir)) {
+                reexports.push(scope_module_hir);
+            } else {
+                dependencies_callback(scope_module_hir);
+            }
+        }
+
+        if let Some(node_idx) = node_idx {
+            for reexport_hir in reexports {
+                let Some(reexport_idx) = self.add_node_if_necessary(reexport_hir) else {
+                    continue;
+                };
+
+                self.add_edge(node_idx, reexport_idx, Edge::Reexports);
+            }
         }
 
         node_idx
@@ -266,9 +425,109 @@ impl<'a> GraphBuilder<'a> {
             dependencies_callback,
         );
 
+        if self.options.body_deps || self.options.calls {
+            let body_deps = self.options.body_deps;
+            let mut callees: Vec<hir::ModuleDef> = vec![];
+
+            self.walk_and_push_body(function_hir, &mut |module_def_hir| {
+                if body_deps {
+                    dependencies_callback(module_def_hir);
+                }
+
+                if let hir::ModuleDef::Function(_) = module_def_hir {
+                    callees.push(module_def_hir);
+                }
+            });
+
+            if self.options.calls {
+                self.add_call_edges(node_idx, callees);
+            }
+        }
+
         Some(node_idx)
     }
 
+    /// Pushes the items referenced within the function's body
+    /// (i.e. called functions, referenced consts and statics, as well as constructed types),
+    /// including the ones referenced within the expansions of its macro invocations.
+    fn walk_and_push_body(
+        &self,
+        function_hir: hir::Function,
+        visit: &mut dyn FnMut(hir::ModuleDef),
+    ) {
+        let Some(body) = self
+            .sema
+            .source(function_hir)
+            .and_then(|source| source.value.body())
+        else {
+            return;
+        };
+
+        let mut roots = vec![body.syntax().clone()];
+
+        while let Some(root) = roots.pop() {
+            for node in root.descendants() {
+                // Macro invocations only contain token trees, so we walk their expansions instead
+                // (which only succeeds for macros that can be resolved, e.g. `std`'s require the sysroot):
+                if let Some(macro_call) = ast::MacroCall::cast(node.clone()) {
+                    if let Some(expansion) = self.sema.expand_macro_call(&macro_call) {
+                        roots.push(expansion.value);
+                    }
+                    continue;
+                }
+
+                if let Some(call) = ast::MethodCallExpr::cast(node.clone()) {
+                    if let Some(method_hir) = self.sema.resolve_method_call(&call) {
+                        visit(hir::ModuleDef::Function(method_hir));
+                    }
+                    continue;
+                }
+
+                let Some(path) = ast::Path::cast(node) else {
+                    continue;
+                };
+
+                // Qualifiers (e.g. `Foo` in `Foo::new`) get resolved as part of their parent path:
+                if path.syntax().parent().and_then(ast::Path::cast).is_some() {
+                    continue;
+                }
+
+                let Some(hir::PathResolution::Def(module_def_hir)) = self.sema.resolve_path(&path)
+                else {
+                    continue;
+                };
+
+                match module_def_hir {
+                    hir::ModuleDef::Function(_)
+                    | hir::ModuleDef::Const(_)
+                    | hir::ModuleDef::Static(_)
+                    | hir::ModuleDef::Adt(_) => visit(module_def_hir),
+                    hir::ModuleDef::EnumVariant(variant_hir) => {
+                        visit(hir::ModuleDef::Adt(hir::Adt::Enum(
+                            variant_hir.parent_enum(self.db),
+                        )));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn add_call_edges(&mut self, caller_idx: NodeIndex, callees: Vec<hir::ModuleDef>) {
+        for callee_hir in callees {
+            // Skip callees that have no path (e.g. methods of slices):
+            if analyzer::path(callee_hir, self.db, self.edition).is_none() {
+                continue;
+            }
+
+            let Some(callee_idx) = self.add_node_if_necessary(callee_hir) else {
+                continue;
+            };
+
+            self.add_edge(caller_idx, callee_idx, Edge::Calls);
+        }
+    }
+
     fn process_adt(
         &mut self,
         adt_hir: hir::Adt,
@@ -409,6 +668,11 @@ impl<'a> GraphBuilder<'a> {
             dependencies_callback,
         );
 
+        // Consts are only of interest if we're looking for their uses within function bodies:
+        if self.options.body_deps && const_hir.name(self.db).is_some() {
+            return self.add_node_if_necessary(hir::ModuleDef::Const(const_hir));
+        }
+
         None
     }
 
@@ -431,6 +695,11 @@ impl<'a> GraphBuilder<'a> {
             dependencies_callback,
         );
 
+        // Statics are only of interest if we're looking for their uses within function bodies:
+        if self.options.body_deps {
+            return self.add_node_if_necessary(hir::ModuleDef::Static(static_hir));
+        }
+
         None
     }
 
@@ -448,6 +717,22 @@ impl<'a> GraphBuilder<'a> {
 
         let node_idx = self.add_node_if_necessary(hir::ModuleDef::Trait(trait_hir));
 
+        // Calls through trait objects or generics resolve to the trait's methods,
+        // so we need to add nodes for those (and their default bodies' calls):
+        if let (Some(node_idx), true) = (node_idx, self.options.calls) {
+            for item in trait_hir.items(self.db) {
+                let hir::AssocItem::Function(function_hir) = item else {
+                    continue;
+                };
+
+                let Some(function_idx) = self.process_function(function_hir, &mut |_| {}) else {
+                    continue;
+                };
+
+                self.add_edge(node_idx, function_idx, Edge::Owns);
+            }
+        }
+
         // TODO: walk types?
 
         #[allow(clippy::let_and_return)]
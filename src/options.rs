@@ -59,9 +59,14 @@ pub struct ProjectOptions {
     #[arg(long = "target")]
     pub target: Option<String>,
 
-    /// Path to Cargo.toml.
+    /// Path to Cargo.toml (or to a standalone `.rs` file, e.g. a script).
     #[arg(long = "manifest-path", default_value = ".")]
     pub manifest_path: PathBuf,
+
+    /// Path to a `rust-project.json` describing a non-Cargo project (e.g. one built with Bazel or Buck),
+    /// with `--package` selecting its crate by name or root file.
+    #[arg(long = "project-json", conflicts_with_all = ["manifest_path", "target-group"])]
+    pub project_json: Option<PathBuf>,
 }
 
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
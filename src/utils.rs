@@ -36,3 +36,40 @@ pub fn sanitized_use_tree(
 
     Ok(crate::analyzer::parse_use_tree(&path_expr))
 }
+
+/// Escapes the characters that are not allowed verbatim in XML text and attribute values.
+pub(crate) fn xml_escape(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+
+    for char in string.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// Formats a row of a plain text table, left-aligning its first column
+/// and right-aligning the (numeric) others, padded to the columns' widths.
+pub(crate) fn table_row(cells: &[String], widths: &[usize]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(index, (cell, width))| {
+            if index == 0 {
+                format!("{cell:<width$}")
+            } else {
+                format!("{cell:>width$}")
+            }
+        })
+        .collect();
+
+    cells.join("  ")
+}
@@ -0,0 +1,39 @@
+use cargo_modules::analysis::{AnalysisOptions, EdgeKind, Target, analyze};
+
+fn options(project: &str) -> AnalysisOptions {
+    AnalysisOptions {
+        manifest_path: format!("tests/projects/{project}").into(),
+        ..AnalysisOptions::default()
+    }
+}
+
+#[test]
+fn default() {
+    let options = AnalysisOptions {
+        target: Target::Lib,
+        ..options("all_targets")
+    };
+
+    let analysis = analyze(&options).unwrap();
+
+    insta::assert_debug_snapshot!(analysis);
+}
+
+#[test]
+fn calls() {
+    let options = AnalysisOptions {
+        calls: true,
+        ..options("call_graph")
+    };
+
+    let analysis = analyze(&options).unwrap();
+
+    let calls: Vec<_> = analysis
+        .edges
+        .iter()
+        .filter(|edge| edge.kind == EdgeKind::Calls)
+        .map(|edge| format!("{} -> {}", edge.source, edge.target))
+        .collect();
+
+    insta::assert_debug_snapshot!(calls);
+}
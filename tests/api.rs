@@ -0,0 +1,53 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "api \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod colors {
+    mod ansi {
+        test_cmd!(
+            args: "api",
+            success: true,
+            color_mode: ColorMode::Ansi,
+            project: api_surface
+        );
+    }
+}
+
+mod text {
+    test_cmd!(
+        args: "api",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: api_surface
+    );
+}
+
+mod format {
+    mod json {
+        test_cmd!(
+            args: "api \
+                    --format json",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: api_surface
+        );
+    }
+}
+
+mod members {
+    test_cmd!(
+        args: "api",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: api_members
+    );
+}
@@ -0,0 +1,76 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "calls \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod default {
+    test_cmd!(
+        args: "calls",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: call_graph
+    );
+}
+
+mod no_externs {
+    test_cmd!(
+        args: "calls \
+                --no-externs",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: call_graph
+    );
+}
+
+mod no_owns {
+    test_cmd!(
+        args: "calls \
+                --no-externs \
+                --no-owns",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: call_graph
+    );
+}
+
+mod macros {
+    test_cmd!(
+        args: "calls",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: macro_calls
+    );
+}
+
+mod focus_on {
+    mod callee {
+        test_cmd!(
+            args: "calls \
+                    --no-externs \
+                    --focus-on \"call_graph::shapes::Square::side\"",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: call_graph
+        );
+    }
+
+    mod caller {
+        test_cmd!(
+            args: "calls \
+                    --no-externs \
+                    --focus-on \"call_graph::report::total\" \
+                    --max-depth 1",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: call_graph
+        );
+    }
+}
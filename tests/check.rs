@@ -0,0 +1,70 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "check \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod colors {
+    mod ansi {
+        test_cmd!(
+            args: "check",
+            success: false,
+            color_mode: ColorMode::Ansi,
+            project: layering
+        );
+    }
+}
+
+mod violations {
+    test_cmd!(
+        args: "check",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: layering
+    );
+}
+
+mod no_violations {
+    test_cmd!(
+        args: "check \
+                --config lenient.toml",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: layering
+    );
+}
+
+mod invalid_config {
+    test_cmd!(
+        args: "check \
+                --config invalid.toml",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: layering
+    );
+}
+
+mod missing_config {
+    test_cmd!(
+        args: "check",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod reexports {
+    test_cmd!(
+        args: "check",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: reexport_cycle
+    );
+}
@@ -0,0 +1,72 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "cycles \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod no_cycles {
+    test_cmd!(
+        args: "cycles",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod cycles {
+    test_cmd!(
+        args: "cycles",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: cycles
+    );
+}
+
+mod granularity {
+    mod types {
+        test_cmd!(
+            args: "cycles \
+                    --granularity types",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: cycles
+        );
+    }
+
+    mod modules {
+        test_cmd!(
+            args: "cycles \
+                    --granularity modules",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: cycles
+        );
+    }
+
+    mod top_level_modules {
+        test_cmd!(
+            args: "cycles \
+                    --granularity top-level-modules",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: cycles
+        );
+    }
+}
+
+mod reexports {
+    test_cmd!(
+        args: "cycles \
+                --granularity modules",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: reexport_cycle
+    );
+}
@@ -476,6 +476,203 @@ mod max_depth {
     }
 }
 
+mod format {
+    mod json {
+        test_cmd!(
+            args: "dependencies \
+                    --format json \
+                    --no-sysroot",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod mermaid {
+        test_cmd!(
+            args: "dependencies \
+                    --format mermaid \
+                    --no-sysroot",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod graphml {
+        test_cmd!(
+            args: "dependencies \
+                    --format graphml \
+                    --no-sysroot",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod gexf {
+        test_cmd!(
+            args: "dependencies \
+                    --format gexf \
+                    --no-sysroot",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod plantuml {
+        test_cmd!(
+            args: "dependencies \
+                    --format plantuml \
+                    --no-sysroot",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+}
+
+mod output {
+    #[test]
+    fn svg() {
+        let path = std::env::temp_dir().join("cargo-modules-output-svg.svg");
+        let _ = std::fs::remove_file(&path);
+
+        let args: Vec<String> = vec![
+            "dependencies".to_owned(),
+            "--no-sysroot".to_owned(),
+            "--layout".to_owned(),
+            "dot".to_owned(),
+            "--splines".to_owned(),
+            "spline".to_owned(),
+            "--output".to_owned(),
+            path.display().to_string(),
+        ];
+        let cmd = crate::util::cmd("smoke", args.iter());
+
+        let (stdout, _stderr) = crate::util::output(cmd, true);
+        assert!(stdout.is_empty());
+
+        let svg = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("smoke"));
+    }
+
+    mod unsupported_extension {
+        test_cmd!(
+            args: "dependencies \
+                    --layout dot \
+                    --splines spline \
+                    --output graph.png",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod unsupported_layout {
+        test_cmd!(
+            args: "dependencies \
+                    --output graph.svg",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod unsupported_splines {
+        test_cmd!(
+            args: "dependencies \
+                    --layout dot \
+                    --output graph.svg",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+
+    mod unsupported_format {
+        test_cmd!(
+            args: "dependencies \
+                    --layout dot \
+                    --splines spline \
+                    --format json \
+                    --output graph.svg",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+}
+
+mod acyclic {
+    mod granularity {
+        mod items {
+            test_cmd!(
+                args: "dependencies \
+                        --no-sysroot \
+                        --layout none \
+                        --acyclic",
+                success: false,
+                color_mode: ColorMode::Plain,
+                project: cycles
+            );
+
+            test_cmd!(
+                args: "dependencies \
+                        --no-sysroot \
+                        --layout none \
+                        --acyclic",
+                success: true,
+                color_mode: ColorMode::Plain,
+                project: nested_modules
+            );
+        }
+
+        mod modules {
+            test_cmd!(
+                args: "dependencies \
+                        --no-sysroot \
+                        --layout none \
+                        --acyclic \
+                        --granularity modules",
+                success: false,
+                color_mode: ColorMode::Plain,
+                project: cycles
+            );
+        }
+
+        mod top_level_modules {
+            test_cmd!(
+                args: "dependencies \
+                        --no-sysroot \
+                        --layout none \
+                        --acyclic \
+                        --granularity top-level-modules",
+                success: true,
+                color_mode: ColorMode::Plain,
+                project: cycles
+            );
+        }
+
+        mod child_depending_on_parent {
+            test_cmd!(
+                args: "dependencies \
+                        --no-sysroot \
+                        --layout none \
+                        --acyclic \
+                        --granularity modules",
+                success: true,
+                color_mode: ColorMode::Plain,
+                project: nested_modules
+            );
+        }
+    }
+}
+
 mod fields {
     test_cmds!(
         args: "dependencies",
@@ -503,6 +700,81 @@ mod functions {
     );
 }
 
+mod impls {
+    mod default {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: trait_impls
+        );
+    }
+
+    mod foreign {
+        test_cmd!(
+            args: "dependencies \
+                    --no-fns \
+                    --no-modules",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: trait_impls
+        );
+    }
+
+    mod no_impls {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs \
+                    --no-impls",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: trait_impls
+        );
+    }
+
+    mod no_uses {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs \
+                    --no-uses",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: trait_impls
+        );
+    }
+
+    mod no_uses_no_impls {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs \
+                    --no-uses \
+                    --no-impls",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: trait_impls
+        );
+    }
+}
+
+mod reexports {
+    test_cmd!(
+        args: "dependencies --no-sysroot",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: api_surface
+    );
+
+    mod aliased {
+        test_cmd!(
+            args: "dependencies --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: reexport_aliases
+        );
+    }
+}
+
 mod github {
     mod issue_79 {
         test_cmd!(
@@ -579,3 +851,171 @@ mod github {
         );
     }
 }
+
+mod body_deps {
+    mod without_body_deps {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: body_deps
+        );
+    }
+
+    mod with_body_deps {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs \
+                    --body-deps",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: body_deps
+        );
+    }
+
+    mod within_macros {
+        test_cmd!(
+            args: "dependencies \
+                    --no-fns \
+                    --no-traits \
+                    --body-deps",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: macro_calls
+        );
+    }
+}
+
+mod workspace {
+    mod default {
+        test_cmd!(
+            args: "dependencies \
+                    --workspace \
+                    --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: workspace_deps
+        );
+    }
+
+    mod modules {
+        test_cmd!(
+            args: "dependencies \
+                    --workspace \
+                    --no-externs \
+                    --no-fns \
+                    --no-types",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: workspace_deps
+        );
+    }
+
+    mod focus_on {
+        test_cmd!(
+            args: "dependencies \
+                    --workspace \
+                    --no-externs \
+                    --focus-on \"store::users\"",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: workspace_deps
+        );
+    }
+
+    mod without_workspace {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: workspace_deps
+        );
+    }
+}
+
+mod all_targets {
+    mod default {
+        test_cmd!(
+            args: "dependencies \
+                    --all-targets \
+                    --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: all_targets
+        );
+    }
+
+    mod modules {
+        test_cmd!(
+            args: "dependencies \
+                    --all-targets \
+                    --no-externs \
+                    --no-fns \
+                    --no-types",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: all_targets
+        );
+    }
+
+    mod without_all_targets {
+        test_cmd!(
+            args: "dependencies \
+                    --no-externs",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: all_targets
+        );
+    }
+}
+
+mod project_json {
+    mod default {
+        test_cmd!(
+            args: "dependencies \
+                    --project-json rust-project.json \
+                    --package beta",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: project_json
+        );
+    }
+
+    mod workspace {
+        test_cmd!(
+            args: "dependencies \
+                    --project-json rust-project.json \
+                    --workspace \
+                    --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: project_json
+        );
+    }
+}
+
+mod detached_file {
+    mod default {
+        test_cmd!(
+            args: "dependencies \
+                    --manifest-path script.rs \
+                    --no-externs",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: detached_file
+        );
+    }
+
+    mod workspace {
+        test_cmd!(
+            args: "dependencies \
+                    --manifest-path script.rs \
+                    --workspace",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: detached_file
+        );
+    }
+}
@@ -0,0 +1,57 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "diff \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod unchanged {
+    test_cmd!(
+        args: "diff \
+                --against .",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: graph_diff
+    );
+}
+
+mod changed {
+    test_cmd!(
+        args: "diff \
+                --against ../graph_diff_base",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: graph_diff
+    );
+}
+
+mod format {
+    mod dot {
+        test_cmd!(
+            args: "diff \
+                    --against ../graph_diff_base \
+                    --format dot",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: graph_diff
+        );
+    }
+}
+
+mod project_json {
+    test_cmd!(
+        args: "diff \
+                --project-json rust-project.json \
+                --package alpha \
+                --against ../graph_diff_base",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: project_json
+    );
+}
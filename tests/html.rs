@@ -0,0 +1,22 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "html \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod page {
+    test_cmd!(
+        args: "html \
+                --no-sysroot",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: readme_tree_example
+    );
+}
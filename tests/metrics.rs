@@ -0,0 +1,66 @@
+#[macro_use]
+mod util;
+
+mod help {
+    test_cmd!(
+        args: "metrics \
+                --help",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: smoke
+    );
+}
+
+mod metrics {
+    test_cmd!(
+        args: "metrics",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: layering
+    );
+}
+
+mod sort_by {
+    mod instability {
+        test_cmd!(
+            args: "metrics \
+                    --sort-by instability \
+                    --sort-reversed",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: layering
+        );
+    }
+
+    mod afferent {
+        test_cmd!(
+            args: "metrics \
+                    --sort-by afferent \
+                    --sort-reversed",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: layering
+        );
+    }
+}
+
+mod format {
+    mod json {
+        test_cmd!(
+            args: "metrics \
+                    --format json",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: layering
+        );
+    }
+}
+
+mod reexports {
+    test_cmd!(
+        args: "metrics",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: reexport_cycle
+    );
+}
@@ -57,3 +57,49 @@ mod orphans {
         project: orphans
     );
 }
+
+mod format {
+    mod json {
+        test_cmd!(
+            args: "orphans \
+                    --format json",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: orphans
+        );
+    }
+
+    mod sarif {
+        test_cmd!(
+            args: "orphans \
+                    --format sarif \
+                    --deny",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: orphans
+        );
+    }
+}
+
+mod project_json {
+    test_cmd!(
+        args: "orphans \
+                --project-json rust-project.json \
+                --package alpha",
+        success: false,
+        color_mode: ColorMode::Plain,
+        project: project_json
+    );
+
+    // Runs the command from within another project's directory:
+    mod outside_project_dir {
+        test_cmd!(
+            args: "orphans \
+                    --project-json ../project_json/rust-project.json \
+                    --package alpha",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: smoke
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use all_targets::engine::Engine;
+
+fn run(engine: Engine) -> Engine {
+    engine
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use all_targets::config::{self, Config};
+
+fn main() {
+    let _config: Config = config::load();
+}
@@ -0,0 +1,21 @@
+pub mod config {
+    pub struct Config;
+
+    pub fn load() -> Config {
+        Config
+    }
+}
+
+pub mod engine {
+    use crate::config::Config;
+
+    pub struct Engine;
+
+    pub fn start(_config: Config) -> Engine {
+        Engine
+    }
+}
+
+pub mod unused {
+    pub struct Unused;
+}
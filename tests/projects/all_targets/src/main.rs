@@ -0,0 +1,5 @@
+use all_targets::{config, engine};
+
+fn main() {
+    let _engine: engine::Engine = engine::start(config::load());
+}
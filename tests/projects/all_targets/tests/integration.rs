@@ -0,0 +1,10 @@
+use all_targets::config::Config;
+
+fn config() -> Config {
+    all_targets::config::load()
+}
+
+#[test]
+fn loads() {
+    let _config = config();
+}
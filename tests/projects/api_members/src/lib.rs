@@ -0,0 +1,54 @@
+mod shapes {
+    pub enum Shape {
+        Circle { radius: f64 },
+        Square(f64),
+    }
+
+    pub struct Point {
+        pub x: f64,
+        pub y: f64,
+        tag: u8,
+    }
+
+    pub struct Meters(pub f64, u8);
+
+    impl Point {
+        pub const ORIGIN: Point = Point { x: 0.0, y: 0.0, tag: 0 };
+
+        pub fn new(x: f64, y: f64) -> Self {
+            Self { x, y, tag: 0 }
+        }
+
+        pub(crate) fn tag(&self) -> u8 {
+            self.tag
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    impl Default for Point {
+        fn default() -> Self {
+            Self::ORIGIN
+        }
+    }
+
+    pub trait Area {
+        type Unit;
+
+        const SIDES: usize;
+
+        fn area(&self) -> f64;
+    }
+
+    impl Area for Shape {
+        type Unit = Meters;
+
+        const SIDES: usize = 0;
+
+        fn area(&self) -> f64 {
+            0.0
+        }
+    }
+}
+
+pub use shapes::{Area, Meters, Point, Shape};
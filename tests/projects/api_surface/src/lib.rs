@@ -0,0 +1,40 @@
+mod internal {
+    pub mod widgets {
+        pub struct Button;
+
+        pub(crate) struct Secret;
+
+        pub fn render(button: &Button) {}
+    }
+
+    pub trait Theme {}
+}
+
+pub mod prelude {
+    pub use crate::internal::widgets::{render, Button};
+
+    pub use crate::internal::Theme as DefaultTheme;
+
+    pub(crate) use crate::internal::widgets::Secret;
+}
+
+pub mod layout {
+    pub struct Grid;
+
+    struct Cell;
+
+    pub mod flex {
+        pub struct Row;
+    }
+}
+
+pub use layout::Grid;
+
+pub use layout as layouts;
+
+pub const VERSION: &str = "1.0";
+
+#[macro_export]
+macro_rules! button {
+    () => {};
+}
@@ -0,0 +1,42 @@
+pub mod config {
+    pub const LIMIT: usize = 10;
+
+    pub struct Config {
+        pub limit: usize,
+    }
+
+    impl Config {
+        pub fn new() -> Self {
+            Config { limit: LIMIT }
+        }
+
+        pub fn limit(&self) -> usize {
+            self.limit
+        }
+    }
+}
+
+pub mod engine {
+    use crate::config::Config;
+
+    pub static GREETING: &str = "hello";
+
+    pub struct Wrapper(pub usize);
+
+    pub enum Mode {
+        Fast,
+        Slow(usize),
+    }
+
+    fn helper() -> usize {
+        GREETING.len()
+    }
+
+    // Neither takes nor returns any of the crate's types:
+    pub fn run() {
+        let config = Config::new();
+        let limit = config.limit();
+        let _wrapper = Wrapper(helper());
+        let _mode = Mode::Slow(limit);
+    }
+}
@@ -0,0 +1,52 @@
+pub mod shapes {
+    pub trait Shape {
+        fn area(&self) -> f64;
+
+        fn describe(&self) -> f64 {
+            self.area()
+        }
+    }
+
+    pub struct Square(pub f64);
+
+    impl Square {
+        pub fn new(side: f64) -> Self {
+            Self(side)
+        }
+
+        fn side(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.side() * self.side()
+        }
+    }
+
+    // Never called, and owns no functions:
+    pub struct Unused;
+}
+
+pub mod report {
+    use crate::shapes::{Shape, Square};
+
+    fn total(first: &dyn Shape, second: &dyn Shape) -> f64 {
+        first.area() + second.area()
+    }
+
+    fn largest(areas: &[f64]) -> f64 {
+        areas.iter().copied().fold(0.0, f64::max)
+    }
+
+    pub fn print() {
+        let square = Square::new(2.0);
+        let _ = square.area();
+        let _ = square.describe();
+        let _ = total(&square, &square);
+        let _ = largest(&[square.area()]);
+    }
+
+    pub fn unrelated() {}
+}
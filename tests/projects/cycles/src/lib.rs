@@ -0,0 +1,62 @@
+pub mod shapes {
+    pub struct Polygon<'a> {
+        pub edge: &'a Edge<'a>,
+    }
+
+    pub struct Edge<'a> {
+        pub start: &'a Vertex<'a>,
+        pub end: &'a Vertex<'a>,
+    }
+
+    pub struct Vertex<'a> {
+        pub polygon: &'a Polygon<'a>,
+    }
+}
+
+pub mod parity {
+    pub struct Even;
+
+    pub struct Odd;
+
+    impl Even {
+        pub fn next(self) -> Odd {
+            Odd
+        }
+    }
+
+    impl Odd {
+        pub fn next(self) -> Even {
+            Even
+        }
+    }
+}
+
+pub mod family {
+    pub struct Parent<'a> {
+        pub child: &'a Child<'a>,
+    }
+
+    pub struct Child<'a> {
+        pub parent: &'a Parent<'a>,
+    }
+}
+
+pub mod list {
+    pub struct Link<'a> {
+        pub next: &'a Link<'a>,
+    }
+}
+
+pub mod network {
+    pub mod nodes {
+        pub struct Host<'a> {
+            pub route: &'a super::routes::Route<'a>,
+        }
+    }
+
+    pub mod routes {
+        pub struct Route<'a> {
+            pub host: &'a super::nodes::Host<'a>,
+        }
+    }
+}
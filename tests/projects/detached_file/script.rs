@@ -0,0 +1,20 @@
+mod geometry {
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    pub fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+}
+
+mod render {
+    use crate::geometry::Point;
+
+    pub fn draw(_point: &Point) {}
+}
+
+fn main() {
+    render::draw(&geometry::origin());
+}
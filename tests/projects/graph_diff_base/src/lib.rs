@@ -0,0 +1,23 @@
+pub mod shapes {
+    pub struct Circle {
+        pub radius: f64,
+    }
+
+    pub struct Square {
+        pub side: f64,
+    }
+
+    pub fn area(circle: &Circle) -> f64 {
+        circle.radius * circle.radius * 3.14
+    }
+}
+
+pub mod render {
+    use crate::{legacy::Canvas, shapes::Circle};
+
+    pub fn draw(canvas: &mut Canvas, circle: &Circle) {}
+}
+
+pub mod legacy {
+    pub struct Canvas;
+}
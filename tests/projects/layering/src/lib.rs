@@ -0,0 +1,51 @@
+pub mod app {
+    use crate::{domain::Order, infra::Database};
+
+    pub struct Settings;
+
+    pub fn run(database: &Database) -> Order {
+        Order::new(database)
+    }
+}
+
+pub mod domain {
+    use crate::infra::Database;
+
+    pub struct Order {
+        pub id: u32,
+    }
+
+    impl Order {
+        pub fn new(database: &Database) -> Self {
+            Self {
+                id: database.next_id(),
+            }
+        }
+    }
+
+    pub struct Customer {
+        pub database: Database,
+    }
+
+    pub trait Repository {}
+}
+
+pub mod infra {
+    pub struct Database {
+        pub settings: crate::app::Settings,
+    }
+
+    impl Database {
+        pub fn next_id(&self) -> u32 {
+            0
+        }
+    }
+
+    pub mod cache {
+        use super::Database;
+
+        pub struct Cache {
+            pub database: Database,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+macro_rules! twice {
+    ($value:expr) => {
+        $crate::math::add($value, $value)
+    };
+}
+
+pub mod math {
+    pub const ZERO: i32 = 0;
+
+    pub fn add(lhs: i32, rhs: i32) -> i32 {
+        lhs + rhs
+    }
+
+    pub fn square(value: i32) -> i32 {
+        value * value
+    }
+}
+
+pub mod report {
+    use crate::math;
+
+    // Only calls functions from within macro invocations:
+    pub fn values() -> Vec<i32> {
+        vec![math::square(2), twice!(math::ZERO)]
+    }
+
+    pub fn summary() -> String {
+        format!("{}", math::square(3))
+    }
+
+    pub fn check() {
+        assert_eq!(values().len(), 2, "{}", summary());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod a {
+    pub struct Foo;
+
+    pub mod b {
+        pub fn f(_: super::Foo) {}
+    }
+}
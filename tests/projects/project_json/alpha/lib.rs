@@ -0,0 +1,11 @@
+pub mod shapes {
+    pub struct Circle {
+        pub radius: f64,
+    }
+
+    pub fn unit() -> Circle {
+        Circle { radius: 1.0 }
+    }
+}
+
+mod inline {}
@@ -0,0 +1,7 @@
+mod report {
+    use alpha::shapes::Circle;
+
+    pub fn describe(_circle: &Circle) {}
+}
+
+fn main() {}
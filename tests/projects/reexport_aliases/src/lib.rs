@@ -0,0 +1,11 @@
+pub mod shapes {
+    pub struct Circle;
+}
+
+pub mod prelude {
+    // Imported both privately (hence used) and publicly under another name (hence re-exported):
+    #[allow(unused_imports)]
+    use crate::shapes::Circle;
+
+    pub use crate::shapes::Circle as Round;
+}
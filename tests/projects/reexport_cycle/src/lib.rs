@@ -0,0 +1,11 @@
+pub mod domain {
+    pub struct Order;
+
+    pub use crate::infra::Database;
+}
+
+pub mod infra {
+    pub struct Database;
+
+    pub use crate::domain::Order;
+}
@@ -0,0 +1,29 @@
+pub mod shapes {
+    pub struct Circle {
+        pub radius: f64,
+    }
+
+    pub(crate) struct Square {
+        pub side: f64,
+    }
+
+    pub fn area(square: &Square) -> f64 {
+        square.side * square.side
+    }
+}
+
+pub mod render {
+    use crate::shapes::Circle;
+
+    pub struct Canvas;
+
+    pub fn draw(canvas: &mut Canvas, circle: &Circle) {}
+
+    pub fn clear(canvas: &mut Canvas) {}
+
+    pub mod viewport {
+        pub mod transform {
+            pub(in crate::render) fn scale(factor: f64) {}
+        }
+    }
+}
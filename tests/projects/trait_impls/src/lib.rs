@@ -0,0 +1,60 @@
+use std::fmt;
+
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+pub trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+// Inherent (and derived) impls only:
+#[derive(Clone)]
+pub struct Point;
+
+impl Point {
+    pub fn origin() -> Self {
+        Point
+    }
+}
+
+// Trait impls:
+pub struct Circle;
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "circle")
+    }
+}
+
+// Generic trait impl:
+pub struct Celsius;
+
+impl Convert<f64> for Celsius {
+    fn convert(&self) -> f64 {
+        0.0
+    }
+}
+
+// Blanket impls:
+impl<T: Shape> Describe for T {
+    fn describe(&self) -> String {
+        String::new()
+    }
+}
+
+impl<T: Shape> Convert<u8> for T {
+    fn convert(&self) -> u8 {
+        0
+    }
+}
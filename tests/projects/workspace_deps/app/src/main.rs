@@ -0,0 +1,14 @@
+use model::user::User;
+use store::users::UserStore;
+
+fn greet(user: &User) {
+    println!("Hello, {}!", user.name);
+}
+
+fn main() {
+    let store = UserStore { users: vec![] };
+
+    if let Some(user) = store.find("ferris") {
+        greet(user);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod user {
+    pub struct User {
+        pub name: String,
+    }
+}
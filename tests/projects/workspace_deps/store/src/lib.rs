@@ -0,0 +1,13 @@
+pub mod users {
+    use model::user::User;
+
+    pub struct UserStore {
+        pub users: Vec<User>,
+    }
+
+    impl UserStore {
+        pub fn find(&self, name: &str) -> Option<&User> {
+            self.users.iter().find(|user| user.name == name)
+        }
+    }
+}
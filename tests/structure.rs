@@ -488,6 +488,87 @@ mod sort_reversed {
     );
 }
 
+mod format {
+    mod json {
+        test_cmd!(
+            args: "structure \
+                    --format json",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: smoke_structure
+        );
+    }
+}
+
+mod stats {
+    mod text {
+        test_cmd!(
+            args: "structure \
+                    --stats \
+                    --cfg-test",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: readme_tree_example
+        );
+    }
+
+    mod json {
+        test_cmd!(
+            args: "structure \
+                    --stats \
+                    --format json",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: readme_tree_example
+        );
+    }
+}
+
+mod diff {
+    mod text {
+        test_cmd!(
+            args: "structure \
+                    --diff ../structure_diff_base",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: structure_diff
+        );
+    }
+
+    mod json {
+        test_cmd!(
+            args: "structure \
+                    --diff ../structure_diff_base \
+                    --format json",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: structure_diff
+        );
+    }
+
+    mod unchanged {
+        test_cmd!(
+            args: "structure \
+                    --diff .",
+            success: true,
+            color_mode: ColorMode::Plain,
+            project: structure_diff
+        );
+    }
+
+    mod project_json {
+        test_cmd!(
+            args: "structure \
+                    --project-json rust-project.json \
+                    --package alpha \
+                    --diff ../structure_diff_base",
+            success: false,
+            color_mode: ColorMode::Plain,
+            project: project_json
+        );
+    }
+}
+
 mod github {
     mod issue_80 {
         mod tests {
@@ -536,3 +617,62 @@ mod github {
         );
     }
 }
+
+mod project_json {
+    mod pass {
+        mod by_name {
+            test_cmd!(
+                args: "structure \
+                        --project-json rust-project.json \
+                        --package alpha",
+                success: true,
+                color_mode: ColorMode::Plain,
+                project: project_json
+            );
+        }
+
+        mod by_root_file {
+            test_cmd!(
+                args: "structure \
+                        --project-json rust-project.json \
+                        --package beta/main.rs",
+                success: true,
+                color_mode: ColorMode::Plain,
+                project: project_json
+            );
+        }
+    }
+
+    mod fail {
+        mod multiple_crates {
+            test_cmd!(
+                args: "structure \
+                        --project-json rust-project.json",
+                success: false,
+                color_mode: ColorMode::Plain,
+                project: project_json
+            );
+        }
+
+        mod nonexistent_crate {
+            test_cmd!(
+                args: "structure \
+                        --project-json rust-project.json \
+                        --package foobar",
+                success: false,
+                color_mode: ColorMode::Plain,
+                project: project_json
+            );
+        }
+    }
+}
+
+mod detached_file {
+    test_cmd!(
+        args: "structure \
+                --manifest-path script.rs",
+        success: true,
+        color_mode: ColorMode::Plain,
+        project: detached_file
+    );
+}